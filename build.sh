@@ -16,6 +16,8 @@ echo "  ./target/release/x1-solxen run        # Run full pipeline"
 echo "  ./target/release/x1-solxen migrate    # Migrate data only"
 echo "  ./target/release/x1-solxen mint       # Process minting only"
 echo "  ./target/release/x1-solxen generate   # Generate HTML only"
+echo "  ./target/release/x1-solxen watch      # Run pipeline every 5 minutes until stopped"
+echo "  ./target/release/x1-solxen stats      # Print statistics (--json for scripts)"
 echo ""
 echo "Make sure to:"
 echo "1. Place burns.db file in the burn-data/ directory"
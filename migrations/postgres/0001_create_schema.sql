@@ -0,0 +1,41 @@
+-- PostgreSQL counterpart of migrations/sqlite/0001-0004. Timestamps are kept
+-- as RFC3339 text so both backends share row types and ordering semantics.
+CREATE TABLE IF NOT EXISTS burn_records (
+    id BIGSERIAL PRIMARY KEY,
+    signature TEXT UNIQUE NOT NULL,
+    burner TEXT NOT NULL,
+    amount BIGINT NOT NULL,
+    memo TEXT,
+    token TEXT,
+    timestamp TEXT,
+    memo_checked TEXT,
+    created_at TEXT NOT NULL,
+    is_minted BOOLEAN DEFAULT FALSE NOT NULL,
+    minted_time TEXT,
+    minted_signature TEXT,
+    fee_lamports BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS idx_burner ON burn_records(burner);
+CREATE INDEX IF NOT EXISTS idx_amount ON burn_records(amount);
+CREATE INDEX IF NOT EXISTS idx_is_minted ON burn_records(is_minted);
+CREATE INDEX IF NOT EXISTS idx_timestamp ON burn_records(timestamp);
+
+CREATE TABLE IF NOT EXISTS pending_mint_log (
+    burn_signature TEXT PRIMARY KEY NOT NULL,
+    mint_signature TEXT NOT NULL,
+    recent_blockhash TEXT NOT NULL,
+    logged_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS mint_attempts (
+    id BIGSERIAL PRIMARY KEY,
+    burn_signature TEXT NOT NULL,
+    attempted_at TEXT NOT NULL,
+    result TEXT NOT NULL,
+    tx_signature TEXT,
+    error TEXT,
+    fee_lamports BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS idx_mint_attempts_burn_signature ON mint_attempts(burn_signature);
@@ -0,0 +1,4 @@
+-- Amount actually minted for a burn; differs from `amount` once a multiplier or cap applies
+ALTER TABLE burn_records ADD COLUMN minted_amount BIGINT;
+UPDATE burn_records SET minted_amount = amount WHERE is_minted;
+ALTER TABLE pending_mint_log ADD COLUMN minted_amount BIGINT;
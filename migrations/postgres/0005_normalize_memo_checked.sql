@@ -0,0 +1,7 @@
+-- memo_checked means 'Y' = memo verified, 'N' = checked and rejected, NULL = never
+-- checked. Older migrations copied the source spelling verbatim; unrecognized
+-- values are left alone and read as unchecked.
+UPDATE burn_records SET memo_checked = 'Y'
+WHERE UPPER(TRIM(memo_checked)) IN ('Y', 'YES', 'T', 'TRUE', '1');
+UPDATE burn_records SET memo_checked = 'N'
+WHERE UPPER(TRIM(memo_checked)) IN ('N', 'NO', 'F', 'FALSE', '0');
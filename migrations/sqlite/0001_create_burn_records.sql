@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS burn_records (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    signature TEXT UNIQUE NOT NULL,
+    burner TEXT NOT NULL,
+    amount DECIMAL(20,6) NOT NULL,
+    memo TEXT,
+    token TEXT,
+    timestamp DATETIME,
+    memo_checked CHAR(1),
+    created_at DATETIME NOT NULL,
+    is_minted BOOLEAN DEFAULT FALSE NOT NULL,
+    minted_time DATETIME,
+    minted_signature TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_signature ON burn_records(signature);
+CREATE INDEX IF NOT EXISTS idx_burner ON burn_records(burner);
+CREATE INDEX IF NOT EXISTS idx_amount ON burn_records(amount);
+CREATE INDEX IF NOT EXISTS idx_is_minted ON burn_records(is_minted);
+CREATE INDEX IF NOT EXISTS idx_timestamp ON burn_records(timestamp);
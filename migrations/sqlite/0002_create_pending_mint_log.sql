@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS pending_mint_log (
+    burn_signature TEXT PRIMARY KEY NOT NULL,
+    mint_signature TEXT NOT NULL,
+    recent_blockhash TEXT NOT NULL,
+    logged_at DATETIME NOT NULL
+);
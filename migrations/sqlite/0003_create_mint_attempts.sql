@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS mint_attempts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    burn_signature TEXT NOT NULL,
+    attempted_at DATETIME NOT NULL,
+    result TEXT NOT NULL,
+    tx_signature TEXT,
+    error TEXT,
+    fee_lamports INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_mint_attempts_burn_signature ON mint_attempts(burn_signature);
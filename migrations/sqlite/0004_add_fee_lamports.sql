@@ -0,0 +1 @@
+ALTER TABLE burn_records ADD COLUMN fee_lamports INTEGER;
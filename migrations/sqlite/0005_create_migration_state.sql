@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS migration_state (
+    source TEXT PRIMARY KEY NOT NULL,
+    checkpoint INTEGER NOT NULL,
+    updated_at DATETIME NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Serves the report's Recent Activity list, newest mints first
+CREATE INDEX IF NOT EXISTS idx_minted_time ON burn_records(minted_time);
@@ -0,0 +1,22 @@
+#!/bin/bash
+set -e
+
+# Regenerate the .sqlx/ offline query data used by the sqlx::query! macros.
+# Run this after changing any SQL in src/database/sqlite.rs or adding a
+# SQLite migration. The Postgres backend uses runtime-checked queries.
+
+echo "Preparing sqlx offline query data..."
+
+SCHEMA_DB=$(mktemp -d)/schema.db
+cat migrations/sqlite/*.sql | sqlite3 "$SCHEMA_DB"
+
+rm -rf .sqlx
+mkdir -p .sqlx
+
+# Force the query macros to re-expand against the fresh schema
+touch src/database/sqlite.rs
+DATABASE_URL="sqlite:$SCHEMA_DB" SQLX_OFFLINE_DIR="$(pwd)/.sqlx" cargo check
+
+rm -f "$SCHEMA_DB"
+
+echo "Offline query data written to .sqlx/"
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::net::SocketAddr;
+use tower_http::cors::{Any, CorsLayer};
+
+use crate::database::Database;
+use crate::types::{BurnRecord, RecordFilter, RecordStatus, Statistics, WalletOrder, WalletSummary};
+
+const DEFAULT_PAGE_SIZE: i64 = 100;
+const MAX_PAGE_SIZE: i64 = 1000;
+
+#[derive(Deserialize)]
+struct RecordsQuery {
+    limit: Option<i64>,
+    offset: Option<i64>,
+    // Not flattened: serde_urlencoded can't parse numbers through flatten
+    min_amount: Option<u64>,
+    max_amount: Option<u64>,
+    status: Option<RecordStatus>,
+    since: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+struct WalletsQuery {
+    #[serde(default)]
+    order: WalletOrder,
+    limit: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct WalletDetail {
+    summary: WalletSummary,
+    records: Vec<BurnRecord>,
+}
+
+/// Any error surfaces as `{"error": "..."}` with the matching status code
+struct ApiError(StatusCode, String);
+
+impl From<anyhow::Error> for ApiError {
+    fn from(err: anyhow::Error) -> Self {
+        error!("API request failed: {}", err);
+        ApiError(StatusCode::INTERNAL_SERVER_ERROR, "internal error".to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
+
+type ApiResult<T> = std::result::Result<Json<T>, ApiError>;
+
+/// Serve the read-only JSON API until `shutdown` resolves. With no CORS
+/// origins configured any origin may read the API.
+pub async fn serve(
+    addr: SocketAddr,
+    db: Database,
+    cors_origins: &[String],
+    shutdown: impl Future<Output = ()>,
+) -> Result<()> {
+    let cors = CorsLayer::new().allow_methods([Method::GET]);
+    let cors = if cors_origins.is_empty() {
+        cors.allow_origin(Any)
+    } else {
+        let origins = cors_origins
+            .iter()
+            .map(|origin| HeaderValue::from_str(origin))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        cors.allow_origin(origins)
+    };
+
+    let app = Router::new()
+        .route("/api/stats", get(stats))
+        .route("/api/wallets", get(wallets))
+        .route("/api/records", get(records))
+        .route("/api/wallet/:address", get(wallet))
+        .layer(cors)
+        .with_state(db);
+
+    let server = axum::Server::try_bind(&addr)?.serve(app.into_make_service());
+    info!("Serving the API on http://{}/api", server.local_addr());
+
+    server.with_graceful_shutdown(shutdown).await?;
+    info!("API server stopped");
+    Ok(())
+}
+
+async fn stats(State(db): State<Database>) -> ApiResult<Statistics> {
+    Ok(Json(db.get_statistics().await?))
+}
+
+/// `?order=burned|minted|burn_count|last_mint&limit=N`; every wallet by default
+async fn wallets(State(db): State<Database>, Query(query): Query<WalletsQuery>) -> ApiResult<Vec<WalletSummary>> {
+    let limit = query.limit.map(|limit| limit.max(0));
+    Ok(Json(db.get_wallet_summaries_by(query.order, limit).await?))
+}
+
+/// `?limit=N&offset=N`, optionally narrowed by `min_amount`/`max_amount`
+/// (raw units), `status=pending|minted|failed` and an RFC3339 `since`
+async fn records(State(db): State<Database>, Query(query): Query<RecordsQuery>) -> ApiResult<Vec<BurnRecord>> {
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0).max(0);
+    let filter = RecordFilter {
+        min_amount: query.min_amount,
+        max_amount: query.max_amount,
+        status: query.status,
+        since: query.since,
+        burner: None,
+    };
+    Ok(Json(db.get_records_filtered(&filter, Some(limit), offset).await?))
+}
+
+async fn wallet(State(db): State<Database>, Path(address): Path<String>) -> ApiResult<WalletDetail> {
+    let summary = db
+        .get_wallet_summary(&address)
+        .await?
+        .ok_or_else(|| ApiError(StatusCode::NOT_FOUND, format!("no burns recorded for {}", address)))?;
+    let records = db.get_records_for_burner(&address).await?;
+    Ok(Json(WalletDetail { summary, records }))
+}
@@ -1,28 +1,560 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use clap::ValueEnum;
 use dirs::home_dir;
-use std::path::PathBuf;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::types::RecordFilter;
+
+/// Initial colour scheme of the HTML report; `Auto` follows the browser preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    Auto,
+}
+
+/// Which source row wins when several sources hold the same signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DedupeRule {
+    /// Keep the row with the largest amount
+    HighestAmount,
+    /// Keep the row that was recorded first
+    EarliestCreatedAt,
+}
+
+/// How recipients receive their tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DistributionMode {
+    /// Mint new tokens with the mint authority
+    Mint,
+    /// Transfer pre-minted tokens out of `transfer_source`
+    Transfer,
+}
+
+/// X1 network to mint on; picks the RPC, explorer and token mint defaults
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Network {
+    #[value(name = "x1-testnet", alias = "testnet")]
+    X1Testnet,
+    #[value(name = "x1-mainnet", alias = "mainnet")]
+    X1Mainnet,
+    /// No defaults: the RPC URL, X1 explorer and token mint must all be set
+    Custom,
+}
+
+/// Endpoints and token mint a network starts from; each can still be overridden
+pub struct NetworkPreset {
+    pub rpc_url: &'static str,
+    pub explorer_base: &'static str,
+    pub token_mint: Option<&'static str>, // None until the token exists there
+    pub label: &'static str, // Shown after the chain name in the report, e.g. "X1 Testnet"
+}
+
+pub const X1_TESTNET_PRESET: NetworkPreset = NetworkPreset {
+    rpc_url: "https://rpc-testnet.x1.wiki",
+    explorer_base: "https://explorer.x1-testnet.xen.network",
+    token_mint: Some("2oaSsGnq1eNjMavSxh1g2XFqtV7SVYwaRJZaBznMyYJT"),
+    label: "Testnet",
+};
+
+pub const X1_MAINNET_PRESET: NetworkPreset = NetworkPreset {
+    rpc_url: "https://rpc.mainnet.x1.xyz",
+    explorer_base: "https://explorer.mainnet.x1.xyz",
+    token_mint: None,
+    label: "Mainnet",
+};
+
+pub const CUSTOM_PRESET: NetworkPreset = NetworkPreset {
+    rpc_url: "",
+    explorer_base: "",
+    token_mint: None,
+    label: "",
+};
+
+impl Network {
+    pub fn preset(self) -> &'static NetworkPreset {
+        match self {
+            Network::X1Testnet => &X1_TESTNET_PRESET,
+            Network::X1Mainnet => &X1_MAINNET_PRESET,
+            Network::Custom => &CUSTOM_PRESET,
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => f.write_str(value.get_name()),
+            None => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// A setting that cannot work, reported before any command runs
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("token_mint `{0}` is not a valid Solana public key")]
+    InvalidTokenMint(String),
+    #[error("network {network} has no default {setting}; set {how}")]
+    MissingNetworkSetting { network: Network, setting: &'static str, how: &'static str },
+    #[error("x1_rpc_url `{0}` is not a valid http(s) URL")]
+    InvalidRpcUrl(String),
+    #[error("rpc_timeout_secs must be greater than zero")]
+    ZeroRpcTimeout,
+    #[error("keypair_path `{0}` is relative and does not exist; use an absolute path")]
+    UnresolvableKeypairPath(PathBuf),
+    #[error("fee_payer_path `{0}` does not exist")]
+    MissingFeePayer(PathBuf),
+    #[error("distribution_mode transfer needs transfer_source (SOLXEN_TRANSFER_SOURCE)")]
+    MissingTransferSource,
+    #[error("transfer_source `{0}` is not a valid Solana public key")]
+    InvalidTransferSource(String),
+    #[error("min_burn_amount must be greater than zero")]
+    ZeroMinBurnAmount,
+    #[error("max_burn_amount {max} is below min_burn_amount {min}")]
+    MaxBelowMinBurnAmount { min: u64, max: u64 },
+    #[error("mint_delay_max_ms {max} is below mint_delay_min_ms {min}")]
+    MintDelayBounds { min: u64, max: u64 },
+    #[error("mint_multiplier must be greater than zero, got {0}")]
+    InvalidMintMultiplier(Decimal),
+    #[error("recipient override for {burner} points to `{recipient}`, which is not a valid Solana public key")]
+    InvalidRecipientOverride { burner: String, recipient: String },
+}
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
-    pub source_db_path: PathBuf,
-    pub x1_rpc_url: String,
-    pub token_mint: String,
+    pub sqlite_busy_timeout_ms: u64, // How long a SQLite writer waits for a lock (SOLXEN_SQLITE_BUSY_TIMEOUT_MS)
+    pub source_db_paths: Vec<PathBuf>, // burns.db shards, migrated in order
+    pub source_table: String, // Table holding the burns in each source database
+    pub network: Network, // Preset the RPC, explorer, token mint and report label default to (SOLXEN_NETWORK, --network)
+    pub x1_rpc_urls: Vec<String>, // Tried in order, failing over on connection errors, timeouts and 429s (SOLXEN_RPC_URL, comma-separated; --rpc-url)
+    pub rpc_timeout_secs: u64, // Longest a single RPC request may take (SOLXEN_RPC_TIMEOUT_SECS)
+    pub token_mint: String, // (SOLXEN_TOKEN_MINT, --token-mint)
+    pub token_decimals: u8, // Decimals amounts are scaled by; checked against the mint at startup
+    pub skip_decimal_check: bool, // Only warn when the mint's decimals differ from token_decimals (--skip-decimal-check)
     pub keypair_path: PathBuf,
+    pub fee_payer_path: Option<PathBuf>, // Pays fees and ATA rent instead of the mint authority (SOLXEN_FEE_PAYER_PATH)
+    pub distribution_mode: DistributionMode, // Mint on demand or transfer from a treasury (SOLXEN_DISTRIBUTION_MODE)
+    pub transfer_source: Option<String>, // Token account transfers are paid from, owned by the authority (SOLXEN_TRANSFER_SOURCE)
+    pub recipient_overrides: HashMap<String, String>, // Burner -> address its tokens go to instead (SOLXEN_RECIPIENT_OVERRIDES file)
     pub min_burn_amount: u64, // Changed to u64 for raw amount (420690000 = 420.69 solXEN)
+    pub max_burn_amount: Option<u64>, // Larger burns are treated as corrupt and skipped (SOLXEN_MAX_BURN_AMOUNT, raw)
+    pub allow_above_max_burn: bool, // Mint burns above max_burn_amount anyway (--force)
+    pub mint_multiplier: Decimal, // Minted amount per burned unit (SOLXEN_MINT_MULTIPLIER)
+    pub mint_cap: Option<u64>, // Most raw units minted for a single burn (SOLXEN_MINT_CAP)
+    pub require_memo: Option<String>, // Only migrate burns whose memo contains this
+    pub exclude_memo: Option<String>, // Skip burns whose memo contains this
+    pub source_token_filter: Option<String>, // Only migrate burns of this token
+    pub source_timezone: Tz, // Zone of source timestamps written without an offset (SOLXEN_SOURCE_TIMEZONE)
+    pub incremental_migration: bool, // Only scan source rows newer than the last checkpoint
+    pub dedupe_sources: bool, // Collapse duplicate signatures across sources before inserting
+    pub dedupe_rule: DedupeRule,
+    pub webhook_url: Option<String>, // POSTed after each successful mint (SOLXEN_WEBHOOK_URL)
+    pub include_burn_memo: bool, // Attach the burn signature as an spl-memo (SOLXEN_INCLUDE_BURN_MEMO)
+    pub theme: Theme,
+    pub report_title: String, // Report heading (SOLXEN_REPORT_TITLE)
+    pub token_symbol: String, // Token name shown in the report (SOLXEN_TOKEN_SYMBOL)
+    pub source_chain_name: String, // Chain the burns happen on (SOLXEN_SOURCE_CHAIN_NAME)
+    pub dest_chain_name: String, // Chain the tokens are minted on (SOLXEN_DEST_CHAIN_NAME)
+    pub top_burners: usize, // Wallets in the report's Top Burners leaderboard
+    pub recent_mints: usize, // Mints in the report's Recent Activity list
+    pub per_wallet_pages: bool, // Also write wallet/<address>.html for every wallet
+    pub anonymize: bool, // Mask addresses and signatures in the report (generate --anonymize)
+    pub solana_explorer_base: String, // Burn links in the report (SOLXEN_SOLANA_EXPLORER_BASE)
+    pub x1_explorer_base: String, // Mint links in the report and logs (SOLXEN_X1_EXPLORER_BASE)
+    pub output_dir: PathBuf, // Where the report artifacts are written
+    pub html_file_name: String,
+    pub gzip_output: bool, // Also write .gz copies of the report artifacts
+    pub json_file_name: String,
+    pub report_since: Option<DateTime<Utc>>, // Only report records created or minted after this
+    pub report_filter: RecordFilter, // Only list records matching this (generate --min-amount/--max-amount/--status)
+    pub mint_concurrency: usize, // Records minted in parallel (1 = sequential)
+    pub confirmation_timeout_secs: u64, // How long to poll for a sent mint to confirm (and again to finalize)
+    pub require_finalized_before_mark: bool, // Only mark a burn minted once its mint is finalized (SOLXEN_REQUIRE_FINALIZED)
+    pub mint_delay_ms: u64, // Starting pause after each mint; adapts to rate limits within the bounds below
+    pub mint_delay_min_ms: Option<u64>, // Shortest the pause shrinks to while the RPC keeps up; unset keeps it at mint_delay_ms (SOLXEN_MINT_DELAY_MIN_MS)
+    pub mint_delay_max_ms: u64, // Longest the pause grows to while the RPC rate limits (SOLXEN_MINT_DELAY_MAX_MS)
+    pub max_mint_attempts: u32, // Remint gives up on burns that failed this many times
+    pub mint_limit: Option<usize>, // Mint at most this many records per run
+    pub confirm_each: bool, // Ask on the terminal before each mint (mint --confirm-each)
+    pub ignore_balance: bool, // Mint even when the fee payer can't cover the estimated cost (--ignore-balance)
+    pub fail_on_mint_error: bool, // Exit non-zero when any mint fails (--fail-on-mint-error)
+    pub lock_path: Option<PathBuf>, // Run lock taken while minting; defaults to next to the database (SOLXEN_LOCK_PATH)
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
         let home = home_dir().ok_or_else(|| anyhow::anyhow!("Cannot find home directory"))?;
+        let network = match std::env::var("SOLXEN_NETWORK").ok().filter(|network| !network.is_empty()) {
+            Some(network) => Network::from_str(&network, true)
+                .map_err(|e| anyhow::anyhow!("Invalid SOLXEN_NETWORK: {}", e))?,
+            None => Network::X1Testnet,
+        };
         
-        Ok(Config {
-            database_url: "sqlite:database/sol_burn_x1_mint.db".to_string(),
-            source_db_path: PathBuf::from("burn-data/burns.db"),
-            x1_rpc_url: "https://rpc-testnet.x1.wiki".to_string(),
-            token_mint: "2oaSsGnq1eNjMavSxh1g2XFqtV7SVYwaRJZaBznMyYJT".to_string(),
+        let mut config = Config {
+            // sqlite:<path> (default) or postgres://... (SOLXEN_DATABASE_URL)
+            database_url: std::env::var("SOLXEN_DATABASE_URL")
+                .ok()
+                .filter(|url| !url.is_empty())
+                .unwrap_or_else(|| "sqlite:database/sol_burn_x1_mint.db".to_string()),
+            sqlite_busy_timeout_ms: std::env::var("SOLXEN_SQLITE_BUSY_TIMEOUT_MS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(5000),
+            source_db_paths: vec![PathBuf::from("burn-data/burns.db")],
+            source_table: "burns".to_string(),
+            network,
+            // Network settings are filled in by set_network below
+            x1_rpc_urls: Vec::new(),
+            // The RPC client's own default
+            rpc_timeout_secs: std::env::var("SOLXEN_RPC_TIMEOUT_SECS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(30),
+            token_mint: String::new(),
+            token_decimals: 6,
+            skip_decimal_check: false,
             keypair_path: home.join(".config/solana/id.json"),
+            fee_payer_path: std::env::var("SOLXEN_FEE_PAYER_PATH")
+                .ok()
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from),
+            distribution_mode: match std::env::var("SOLXEN_DISTRIBUTION_MODE").ok().filter(|mode| !mode.is_empty()) {
+                Some(mode) => DistributionMode::from_str(&mode, true)
+                    .map_err(|e| anyhow::anyhow!("Invalid SOLXEN_DISTRIBUTION_MODE: {}", e))?,
+                None => DistributionMode::Mint,
+            },
+            transfer_source: std::env::var("SOLXEN_TRANSFER_SOURCE").ok().filter(|source| !source.is_empty()),
+            recipient_overrides: match std::env::var("SOLXEN_RECIPIENT_OVERRIDES").ok().filter(|path| !path.is_empty()) {
+                Some(path) => load_recipient_overrides(Path::new(&path))?,
+                None => HashMap::new(),
+            },
             min_burn_amount: 420_000_000, // 420 solXEN in raw amount (6 decimals)
-        })
+            max_burn_amount: env_parsed("SOLXEN_MAX_BURN_AMOUNT")?,
+            allow_above_max_burn: false,
+            mint_multiplier: env_parsed("SOLXEN_MINT_MULTIPLIER")?.unwrap_or(Decimal::ONE),
+            mint_cap: env_parsed("SOLXEN_MINT_CAP")?,
+            require_memo: None,
+            exclude_memo: None,
+            source_token_filter: None,
+            source_timezone: match std::env::var("SOLXEN_SOURCE_TIMEZONE").ok().filter(|zone| !zone.is_empty()) {
+                Some(zone) => parse_timezone(&zone).map_err(|e| anyhow::anyhow!("Invalid SOLXEN_SOURCE_TIMEZONE: {}", e))?,
+                None => Tz::UTC,
+            },
+            incremental_migration: true,
+            dedupe_sources: false,
+            dedupe_rule: DedupeRule::HighestAmount,
+            webhook_url: std::env::var("SOLXEN_WEBHOOK_URL").ok().filter(|url| !url.is_empty()),
+            include_burn_memo: env_flag("SOLXEN_INCLUDE_BURN_MEMO"),
+            theme: Theme::Light,
+            report_title: env_or("SOLXEN_REPORT_TITLE", "solXEN is The Second Best"),
+            token_symbol: env_or("SOLXEN_TOKEN_SYMBOL", "solXEN"),
+            source_chain_name: env_or("SOLXEN_SOURCE_CHAIN_NAME", "Solana"),
+            dest_chain_name: env_or("SOLXEN_DEST_CHAIN_NAME", "X1"),
+            top_burners: 10,
+            recent_mints: 20,
+            per_wallet_pages: false,
+            anonymize: false,
+            solana_explorer_base: explorer_base("SOLXEN_SOLANA_EXPLORER_BASE", "https://solscan.io"),
+            x1_explorer_base: String::new(),
+            output_dir: PathBuf::from("."),
+            html_file_name: "index.html".to_string(),
+            gzip_output: false,
+            json_file_name: "index.json".to_string(),
+            report_since: None,
+            report_filter: RecordFilter::default(),
+            mint_concurrency: 1,
+            confirmation_timeout_secs: 60,
+            require_finalized_before_mark: env_flag("SOLXEN_REQUIRE_FINALIZED"),
+            mint_delay_ms: std::env::var("SOLXEN_MINT_DELAY_MS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(2000),
+            mint_delay_min_ms: std::env::var("SOLXEN_MINT_DELAY_MIN_MS")
+                .ok()
+                .and_then(|value| value.parse().ok()),
+            mint_delay_max_ms: std::env::var("SOLXEN_MINT_DELAY_MAX_MS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(30_000),
+            max_mint_attempts: 5,
+            mint_limit: None,
+            confirm_each: false,
+            ignore_balance: false,
+            fail_on_mint_error: false,
+            lock_path: std::env::var("SOLXEN_LOCK_PATH").ok().filter(|path| !path.is_empty()).map(PathBuf::from),
+        };
+        config.set_network(network);
+        Ok(config)
+    }
+    
+    /// Point the RPC, explorer and token mint at `network`'s preset.
+    /// SOLXEN_RPC_URL, SOLXEN_X1_EXPLORER_BASE and SOLXEN_TOKEN_MINT still win.
+    pub fn set_network(&mut self, network: Network) {
+        let preset = network.preset();
+        self.network = network;
+        self.x1_rpc_urls = env_or("SOLXEN_RPC_URL", preset.rpc_url)
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(String::from)
+            .collect();
+        self.x1_explorer_base = explorer_base("SOLXEN_X1_EXPLORER_BASE", preset.explorer_base);
+        self.token_mint = env_or("SOLXEN_TOKEN_MINT", preset.token_mint.unwrap_or_default());
+    }
+}
+
+impl Config {
+    /// Reject settings that would only fail later, halfway through a run
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let missing = |setting, how| ConfigError::MissingNetworkSetting { network: self.network, setting, how };
+        if self.x1_rpc_urls.is_empty() {
+            return Err(missing("x1_rpc_url", "SOLXEN_RPC_URL or --rpc-url"));
+        }
+        if self.x1_explorer_base.is_empty() {
+            return Err(missing("x1_explorer_base", "SOLXEN_X1_EXPLORER_BASE"));
+        }
+        if self.token_mint.is_empty() {
+            return Err(missing("token_mint", "SOLXEN_TOKEN_MINT or --token-mint"));
+        }
+        if Pubkey::from_str(&self.token_mint).is_err() {
+            return Err(ConfigError::InvalidTokenMint(self.token_mint.clone()));
+        }
+        
+        for rpc_url in &self.x1_rpc_urls {
+            let rpc_url_valid = reqwest::Url::parse(rpc_url)
+                .map(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+                .unwrap_or(false);
+            if !rpc_url_valid {
+                return Err(ConfigError::InvalidRpcUrl(rpc_url.clone()));
+            }
+        }
+        
+        if self.rpc_timeout_secs == 0 {
+            return Err(ConfigError::ZeroRpcTimeout);
+        }
+        
+        // A missing keypair is fine (minting is simulated), but a relative path
+        // that does not exist would silently depend on the working directory
+        if self.keypair_path.as_os_str().is_empty()
+            || (self.keypair_path.is_relative() && !self.keypair_path.exists())
+        {
+            return Err(ConfigError::UnresolvableKeypairPath(self.keypair_path.clone()));
+        }
+        
+        // Unlike the authority, a configured fee payer is never optional
+        if let Some(fee_payer_path) = &self.fee_payer_path {
+            if !fee_payer_path.exists() {
+                return Err(ConfigError::MissingFeePayer(fee_payer_path.clone()));
+            }
+        }
+        
+        if self.distribution_mode == DistributionMode::Transfer {
+            match &self.transfer_source {
+                None => return Err(ConfigError::MissingTransferSource),
+                Some(source) if Pubkey::from_str(source).is_err() => {
+                    return Err(ConfigError::InvalidTransferSource(source.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        
+        if self.min_burn_amount == 0 {
+            return Err(ConfigError::ZeroMinBurnAmount);
+        }
+        
+        if let Some(max) = self.max_burn_amount {
+            if max < self.min_burn_amount {
+                return Err(ConfigError::MaxBelowMinBurnAmount { min: self.min_burn_amount, max });
+            }
+        }
+        
+        if let Some(min) = self.mint_delay_min_ms {
+            if self.mint_delay_max_ms < min {
+                return Err(ConfigError::MintDelayBounds { min, max: self.mint_delay_max_ms });
+            }
+        }
+        
+        if self.mint_multiplier <= Decimal::ZERO {
+            return Err(ConfigError::InvalidMintMultiplier(self.mint_multiplier));
+        }
+        
+        for (burner, recipient) in &self.recipient_overrides {
+            if Pubkey::from_str(recipient).is_err() {
+                return Err(ConfigError::InvalidRecipientOverride {
+                    burner: burner.clone(),
+                    recipient: recipient.clone(),
+                });
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether a burn's memo passes the memo filters. A missing memo never
+    /// satisfies `require_memo` and never matches `exclude_memo`.
+    pub fn memo_allowed(&self, memo: Option<&str>) -> bool {
+        if let Some(required) = &self.require_memo {
+            if !memo.is_some_and(|memo| memo.contains(required.as_str())) {
+                return false;
+            }
+        }
+        if let Some(excluded) = &self.exclude_memo {
+            if memo.is_some_and(|memo| memo.contains(excluded.as_str())) {
+                return false;
+            }
+        }
+        true
+    }
+    
+    /// Whether a burn's token passes `source_token_filter`; with a filter set,
+    /// burns without a token are rejected
+    pub fn token_allowed(&self, token: Option<&str>) -> bool {
+        match &self.source_token_filter {
+            Some(filter) => token == Some(filter.as_str()),
+            None => true,
+        }
+    }
+    
+    /// Raw units to mint for a burn: the burned amount times `mint_multiplier`,
+    /// rounded down and limited to `mint_cap`
+    pub fn mint_amount_for(&self, burn_amount: u64) -> Result<u64> {
+        let scaled = (Decimal::from(burn_amount) * self.mint_multiplier)
+            .floor()
+            .to_u64()
+            .ok_or_else(|| anyhow::anyhow!("Mint amount for a burn of {} raw units overflows", burn_amount))?;
+        Ok(self.mint_cap.map_or(scaled, |cap| scaled.min(cap)))
+    }
+    
+    /// Explorer link for an X1 transaction
+    pub fn x1_tx_url(&self, signature: &str) -> String {
+        format!("{}/tx/{}", self.x1_explorer_base, signature)
+    }
+    
+    /// Address that receives the tokens for a burn by `burner`: its override,
+    /// if one is configured, otherwise the burner itself
+    pub fn recipient_for<'a>(&'a self, burner: &'a str) -> &'a str {
+        self.recipient_overrides.get(burner).map_or(burner, String::as_str)
+    }
+    
+    /// Whether a burn is over `max_burn_amount` and not forced through
+    pub fn exceeds_max_burn(&self, amount: u64) -> bool {
+        !self.allow_above_max_burn && self.max_burn_amount.is_some_and(|max| amount > max)
+    }
+    
+    /// The minting threshold in solXEN, for logs
+    pub fn min_burn_amount_solxen(&self) -> Decimal {
+        (Decimal::from(self.min_burn_amount) / Decimal::from(1_000_000)).normalize()
+    }
+}
+
+/// Explorer base URL from `name`, without a trailing slash so paths can be appended
+fn explorer_base(name: &str, default: &str) -> String {
+    std::env::var(name)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| default.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Read recipient overrides from a JSON object mapping burner addresses to the
+/// address that receives their tokens instead
+fn load_recipient_overrides(path: &Path) -> Result<HashMap<String, String>> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read recipient overrides {}: {}", path.display(), e))?;
+    serde_json::from_str(&text)
+        .map_err(|e| anyhow::anyhow!("Invalid recipient overrides {}: {}", path.display(), e))
+}
+
+/// IANA zone name such as `UTC` or `Asia/Shanghai`
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.parse::<Tz>().map_err(|_| format!("unknown time zone {:?} (use an IANA name such as Europe/Berlin)", name))
+}
+
+/// The variable's value, or `default` when unset or empty
+fn env_or(name: &str, default: &str) -> String {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| default.to_string())
+}
+
+/// The variable parsed as `T`; None when unset or empty. A value that doesn't
+/// parse is an error rather than the default, since these settings guard minting.
+fn env_parsed<T>(name: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    parse_setting(name, std::env::var(name).ok())
+}
+
+/// `env_parsed` for a value already read from `name`
+fn parse_setting<T>(name: &str, value: Option<String>) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match value.as_deref().map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|e| anyhow::anyhow!("Invalid {}: {:?} ({})", name, value, e)),
+        None => Ok(None),
+    }
+}
+
+/// True when the variable is set to 1/true/yes (case-insensitive)
+fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn max_burn_amount_must_parse_when_set() {
+        let parse = |value: &str| parse_setting::<u64>("SOLXEN_MAX_BURN_AMOUNT", Some(value.to_string()));
+        assert_eq!(parse("1000000000000").unwrap(), Some(1_000_000_000_000));
+        assert_eq!(parse(" 42 ").unwrap(), Some(42));
+        assert_eq!(parse("").unwrap(), None);
+        assert_eq!(parse_setting::<u64>("SOLXEN_MAX_BURN_AMOUNT", None).unwrap(), None);
+        for invalid in ["1e12", "1_000_000", "420.69", "-1", "lots"] {
+            let error = parse(invalid).unwrap_err().to_string();
+            assert!(error.starts_with("Invalid SOLXEN_MAX_BURN_AMOUNT"), "{}", error);
+            assert!(error.contains(invalid), "{}", error);
+        }
+    }
+    
+    #[test]
+    fn mint_multiplier_and_cap_must_parse_when_set() {
+        let multiplier = |value: &str| parse_setting::<Decimal>("SOLXEN_MINT_MULTIPLIER", Some(value.to_string()));
+        assert_eq!(multiplier("0.5").unwrap(), Some(Decimal::new(5, 1)));
+        assert_eq!(multiplier("2").unwrap(), Some(Decimal::from(2)));
+        for invalid in ["0,5", "half", "1.5x"] {
+            assert!(multiplier(invalid).unwrap_err().to_string().starts_with("Invalid SOLXEN_MINT_MULTIPLIER"));
+        }
+        
+        let cap = |value: &str| parse_setting::<u64>("SOLXEN_MINT_CAP", Some(value.to_string()));
+        assert_eq!(cap("1000000000").unwrap(), Some(1_000_000_000));
+        for invalid in ["1e9", "1,000", "100.5"] {
+            assert!(cap(invalid).unwrap_err().to_string().starts_with("Invalid SOLXEN_MINT_CAP"));
+        }
     }
 }
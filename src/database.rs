@@ -1,214 +1,616 @@
 use anyhow::Result;
-use chrono::Utc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use rusqlite::{params, Connection, Row};
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::types::{BurnRecord, Statistics, WalletSummary};
+use crate::config::Config;
+use crate::types::{
+    BurnRecord, FailedMint, MemoCheck, MintAttempt, NewBurnRecord, PendingMintLogEntry, RecordFilter, Statistics,
+    WalletOrder, WalletSummary,
+};
 
+mod postgres;
+mod sqlite;
+
+use postgres::PostgresStore;
+use sqlite::SqliteStore;
+
+/// Storage operations shared by every destination backend
+#[async_trait]
+pub trait BurnStore: Send + Sync {
+    async fn signature_exists(&self, signature: &str) -> Result<bool>;
+    async fn insert_burn_record(&self, record: &NewBurnRecord) -> Result<()>;
+    /// Unminted burns of at least `min_amount`, fewest failed attempts first
+    /// so repeatedly failing burns don't hold up fresh ones, then oldest first
+    async fn get_pending_mints(&self, min_amount: u64) -> Result<Vec<BurnRecord>>;
+    /// Pending mints of one burner, in the same order; empty when it has none
+    async fn get_pending_mints_for(&self, burner: &str, min_amount: u64) -> Result<Vec<BurnRecord>>;
+    /// Unminted burns with at least one failed mint attempt, oldest first
+    async fn get_failed_mints(&self, burner: Option<&str>) -> Result<Vec<FailedMint>>;
+    /// Mark a burn as minted. Without a `minted_amount` the burn amount is assumed.
+    async fn mark_as_minted(
+        &self,
+        signature: &str,
+        minted_signature: &str,
+        minted_amount: Option<u64>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()>;
+    /// Mark a minted burn as pending again so the next mint run reissues it.
+    /// Only the bookkeeping changes; returns false when no minted burn matched.
+    async fn reset_mint(&self, signature: &str) -> Result<bool>;
+    /// Record a signed mint transaction right before it is broadcast. This is
+    /// the mint intent: `mark_as_minted` removes it in the same transaction
+    /// that marks the burn, so an entry left behind means the outcome is unknown.
+    async fn log_pending_mint(
+        &self,
+        burn_signature: &str,
+        mint_signature: &str,
+        recent_blockhash: &str,
+        minted_amount: u64,
+    ) -> Result<()>;
+    async fn clear_pending_mint(&self, burn_signature: &str) -> Result<()>;
+    async fn get_pending_mint_log(&self) -> Result<Vec<PendingMintLogEntry>>;
+    /// Append one row to the audit trail of mint attempts. A missing `error`
+    /// means the attempt succeeded.
+    async fn record_mint_attempt(
+        &self,
+        burn_signature: &str,
+        tx_signature: Option<&str>,
+        error: Option<&str>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()>;
+    /// Every recorded mint attempt for a burn, oldest first
+    async fn get_attempts_for(&self, burn_signature: &str) -> Result<Vec<MintAttempt>>;
+    /// Newest source `created_at` (unix seconds) already scanned from `source`
+    async fn get_migration_checkpoint(&self, source: &str) -> Result<Option<i64>>;
+    async fn set_migration_checkpoint(&self, source: &str, checkpoint: i64) -> Result<()>;
+    /// Records matching every constraint of `filter`, newest first, at most
+    /// `limit` of them after skipping `offset`
+    async fn get_records_filtered(&self, filter: &RecordFilter, limit: Option<i64>, offset: i64) -> Result<Vec<BurnRecord>>;
+    /// The `limit` most recently minted burns, newest mint first
+    async fn get_recent_mints(&self, limit: i64) -> Result<Vec<BurnRecord>>;
+    async fn get_records_for_burner(&self, burner: &str) -> Result<Vec<BurnRecord>>;
+    async fn get_wallet_summaries(&self) -> Result<Vec<WalletSummary>>;
+    /// Wallet summaries ranked by `order`, at most `limit` of them. Ties keep
+    /// the default burned-amount order.
+    async fn get_wallet_summaries_by(&self, order: WalletOrder, limit: Option<i64>) -> Result<Vec<WalletSummary>>;
+    /// Wallet totals over the records created or minted after `since`
+    async fn get_wallet_summaries_since(&self, since: DateTime<Utc>) -> Result<Vec<WalletSummary>>;
+    async fn get_wallet_summary(&self, burner: &str) -> Result<Option<WalletSummary>>;
+    async fn get_statistics(&self) -> Result<Statistics>;
+    /// Statistics over the records created or minted after `since`
+    async fn get_statistics_since(&self, since: DateTime<Utc>) -> Result<Statistics>;
+}
+
+/// Handle to the destination database. The backend is picked from the scheme
+/// of `database_url`: `postgres://` (or `postgresql://`) uses PostgreSQL,
+/// anything else is a SQLite path with an optional `sqlite:` prefix, where
+/// `sqlite::memory:` keeps the data in memory. Cloning shares the underlying
+/// connection pool.
+#[derive(Clone)]
 pub struct Database {
-    conn: Connection,
+    store: Arc<dyn BurnStore>,
 }
 
 impl Database {
-    pub async fn new(database_path: &str) -> Result<Self> {
-        // Remove sqlite: prefix if present
-        let path = database_path.strip_prefix("sqlite:").unwrap_or(database_path);
-        
-        // Ensure database directory exists
-        if let Some(parent) = std::path::Path::new(path).parent() {
-            std::fs::create_dir_all(parent)?;
+    pub async fn new(config: &Config) -> Result<Self> {
+        let database_url = config.database_url.as_str();
+        let store: Arc<dyn BurnStore> =
+            if Self::is_postgres_url(database_url) {
+                Arc::new(PostgresStore::connect(database_url).await?)
+            } else if is_in_memory_url(database_url) {
+                return Self::new_in_memory().await;
+            } else {
+                let busy_timeout = Duration::from_millis(config.sqlite_busy_timeout_ms);
+                Arc::new(SqliteStore::connect(database_url, busy_timeout).await?)
+            };
+
+        Ok(Database { store })
+    }
+    
+    /// Open an empty, fully migrated SQLite database that lives only as long
+    /// as this handle and its clones; nothing touches the filesystem
+    pub async fn new_in_memory() -> Result<Self> {
+        let store = SqliteStore::connect_in_memory().await?;
+        Ok(Database { store: Arc::new(store) })
+    }
+    
+    /// Where the run lock lives by default: `<database file>.lock` for SQLite,
+    /// `x1-solxen.lock` in the working directory for PostgreSQL, and none for
+    /// an in-memory database
+    pub fn default_lock_path(database_url: &str) -> Option<PathBuf> {
+        if Self::is_postgres_url(database_url) {
+            Some(PathBuf::from("x1-solxen.lock"))
+        } else if is_in_memory_url(database_url) {
+            None
+        } else {
+            let mut path = resolve_sqlite_path(database_url).into_os_string();
+            path.push(".lock");
+            Some(PathBuf::from(path))
         }
-        
-        let conn = Connection::open(path)?;
-        Ok(Database { conn })
-    }
-
-    pub async fn get_pending_mints(&self, min_amount: u64) -> Result<Vec<BurnRecord>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, signature, burner, amount, memo, token, timestamp, memo_checked, 
-                    created_at, is_minted, minted_time, minted_signature 
-             FROM burn_records 
-             WHERE is_minted = FALSE AND amount >= ?1 
-             ORDER BY timestamp ASC"
-        )?;
-
-        let record_iter = stmt.query_map(params![min_amount as i64], |row| {
-            self.row_to_burn_record(row)
-        })?;
-
-        let mut records = Vec::new();
-        for record in record_iter {
-            records.push(record?);
+    }
+    
+    /// Whether the URL names a PostgreSQL server rather than a SQLite database
+    pub fn is_postgres_url(database_url: &str) -> bool {
+        database_url.starts_with("postgres://") || database_url.starts_with("postgresql://")
+    }
+    
+    /// File behind a SQLite URL; None for PostgreSQL and in-memory databases
+    pub fn sqlite_file(database_url: &str) -> Option<PathBuf> {
+        if Self::is_postgres_url(database_url) || is_in_memory_url(database_url) {
+            None
+        } else {
+            Some(resolve_sqlite_path(database_url))
         }
+    }
+    
+    /// Count the records of an existing database without creating it or
+    /// migrating its schema, for read-only diagnostics
+    pub async fn probe(config: &Config) -> Result<i64> {
+        let database_url = config.database_url.as_str();
+        if Self::is_postgres_url(database_url) {
+            PostgresStore::probe(database_url).await
+        } else {
+            SqliteStore::probe(database_url).await
+        }
+    }
+}
+
+impl Deref for Database {
+    type Target = dyn BurnStore;
 
-        Ok(records)
+    fn deref(&self) -> &Self::Target {
+        self.store.as_ref()
     }
+}
+
+// Raw row shapes shared by the backends; timestamps are kept as RFC3339 text
+#[derive(sqlx::FromRow)]
+struct BurnRecordRow {
+    id: Option<i64>,
+    signature: String,
+    burner: String,
+    amount: i64,
+    memo: Option<String>,
+    token: Option<String>,
+    timestamp: Option<String>,
+    memo_checked: Option<String>,
+    created_at: String,
+    is_minted: bool,
+    minted_time: Option<String>,
+    minted_signature: Option<String>,
+    minted_amount: Option<i64>,
+}
+
+// BurnRecordRow plus the number of failed attempts; kept flat for query_as!
+#[derive(sqlx::FromRow)]
+struct FailedMintRow {
+    id: Option<i64>,
+    signature: String,
+    burner: String,
+    amount: i64,
+    memo: Option<String>,
+    token: Option<String>,
+    timestamp: Option<String>,
+    memo_checked: Option<String>,
+    created_at: String,
+    is_minted: bool,
+    minted_time: Option<String>,
+    minted_signature: Option<String>,
+    minted_amount: Option<i64>,
+    failed_attempts: i64,
+}
 
-    pub async fn mark_as_minted(&self, signature: &str, minted_signature: &str) -> Result<()> {
-        self.conn.execute(
-            "UPDATE burn_records SET is_minted = TRUE, minted_time = ?1, minted_signature = ?2 WHERE signature = ?3",
-            params![Utc::now().to_rfc3339(), minted_signature, signature],
-        )?;
-        Ok(())
+#[derive(sqlx::FromRow)]
+struct MintAttemptRow {
+    id: i64,
+    burn_signature: String,
+    attempted_at: String,
+    result: String,
+    tx_signature: Option<String>,
+    error: Option<String>,
+    fee_lamports: Option<i64>,
+}
+
+// Totals are added up separately by each backend, see RawTotal
+#[derive(sqlx::FromRow)]
+struct WalletSummaryRow {
+    burner: String,
+    burn_count: i64,
+    mint_count: i64,
+    first_burn: Option<String>,
+    last_mint: Option<String>,
+}
+
+impl From<BurnRecordRow> for BurnRecord {
+    fn from(row: BurnRecordRow) -> Self {
+        let created_at = parse_timestamp(Some(row.created_at)).unwrap_or_else(Utc::now);
+
+        BurnRecord {
+            id: row.id,
+            signature: row.signature,
+            burner: row.burner,
+            amount: row.amount as u64, // Convert to u64, but keep original raw value
+            memo: row.memo,
+            token: row.token,
+            timestamp: parse_timestamp(row.timestamp),
+            memo_checked: MemoCheck::from_column(row.memo_checked.as_deref()),
+            created_at,
+            is_minted: row.is_minted,
+            minted_time: parse_timestamp(row.minted_time),
+            minted_signature: row.minted_signature,
+            minted_amount: row.minted_amount.map(|amount| amount as u64),
+        }
     }
+}
 
-    pub async fn get_all_records(&self) -> Result<Vec<BurnRecord>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, signature, burner, amount, memo, token, timestamp, memo_checked, 
-                    created_at, is_minted, minted_time, minted_signature 
-             FROM burn_records 
-             ORDER BY timestamp DESC"
-        )?;
+impl From<FailedMintRow> for FailedMint {
+    fn from(row: FailedMintRow) -> Self {
+        let record = BurnRecordRow {
+            id: row.id,
+            signature: row.signature,
+            burner: row.burner,
+            amount: row.amount,
+            memo: row.memo,
+            token: row.token,
+            timestamp: row.timestamp,
+            memo_checked: row.memo_checked,
+            created_at: row.created_at,
+            is_minted: row.is_minted,
+            minted_time: row.minted_time,
+            minted_signature: row.minted_signature,
+            minted_amount: row.minted_amount,
+        };
 
-        let record_iter = stmt.query_map([], |row| {
-            self.row_to_burn_record(row)
-        })?;
+        FailedMint {
+            record: record.into(),
+            failed_attempts: row.failed_attempts,
+        }
+    }
+}
 
-        let mut records = Vec::new();
-        for record in record_iter {
-            records.push(record?);
+impl From<MintAttemptRow> for MintAttempt {
+    fn from(row: MintAttemptRow) -> Self {
+        MintAttempt {
+            id: row.id,
+            burn_signature: row.burn_signature,
+            attempted_at: parse_timestamp(Some(row.attempted_at)).unwrap_or_else(Utc::now),
+            result: row.result,
+            tx_signature: row.tx_signature,
+            error: row.error,
+            fee_lamports: row.fee_lamports.map(|fee| fee as u64),
         }
+    }
+}
 
-        Ok(records)
-    }
-
-    pub async fn get_wallet_summaries(&self) -> Result<Vec<WalletSummary>> {
-        let mut stmt = self.conn.prepare(
-            r#"
-            SELECT 
-                burner,
-                SUM(CASE WHEN is_minted = FALSE THEN amount ELSE 0 END) as total_burned,
-                SUM(CASE WHEN is_minted = TRUE THEN amount ELSE 0 END) as total_minted,
-                COUNT(*) as burn_count,
-                SUM(CASE WHEN is_minted = TRUE THEN 1 ELSE 0 END) as mint_count,
-                MIN(timestamp) as first_burn,
-                MAX(minted_time) as last_mint
-            FROM burn_records 
-            GROUP BY burner 
-            ORDER BY total_burned DESC
-            "#
-        )?;
-
-        let summary_iter = stmt.query_map([], |row| {
-            let wallet_address: String = row.get(0)?;
-            let total_burned_raw: i64 = row.get(1)?;
-            let total_minted_raw: i64 = row.get(2)?;
-            let burn_count: i64 = row.get(3)?;
-            let mint_count: i64 = row.get(4)?;
-            let first_burn_str: Option<String> = row.get(5)?;
-            let last_mint_str: Option<String> = row.get(6)?;
-
-            let total_burned = Decimal::from(total_burned_raw) / Decimal::from(1_000_000);
-            let total_minted = Decimal::from(total_minted_raw) / Decimal::from(1_000_000);
-
-            let first_burn = first_burn_str.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let last_mint = last_mint_str.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-
-            Ok(WalletSummary {
-                wallet_address,
-                total_burned,
-                total_minted,
-                burn_count,
-                mint_count,
-                first_burn,
-                last_mint,
-            })
-        })?;
-
-        let mut summaries = Vec::new();
-        for summary in summary_iter {
-            summaries.push(summary?);
+impl WalletSummaryRow {
+    fn into_summary(self, total_burned: Decimal, total_minted: Decimal) -> WalletSummary {
+        WalletSummary {
+            wallet_address: self.burner,
+            total_burned,
+            total_minted,
+            burn_count: self.burn_count,
+            mint_count: self.mint_count,
+            first_burn: parse_timestamp(self.first_burn),
+            last_mint: parse_timestamp(self.last_mint),
         }
+    }
+}
 
-        Ok(summaries)
-    }
-
-    pub async fn get_statistics(&self) -> Result<Statistics> {
-        let total_records: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM burn_records",
-            [],
-            |row| row.get(0),
-        )?;
-
-        let total_burned_raw: i64 = self.conn.query_row(
-            "SELECT COALESCE(SUM(amount), 0) FROM burn_records",
-            [],
-            |row| row.get(0),
-        )?;
-
-        let total_minted_raw: i64 = self.conn.query_row(
-            "SELECT COALESCE(SUM(amount), 0) FROM burn_records WHERE is_minted = TRUE",
-            [],
-            |row| row.get(0),
-        )?;
-
-        let unique_wallets: i64 = self.conn.query_row(
-            "SELECT COUNT(DISTINCT burner) FROM burn_records",
-            [],
-            |row| row.get(0),
-        )?;
-
-        let pending_mints: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM burn_records WHERE is_minted = FALSE",
-            [],
-            |row| row.get(0),
-        )?;
-
-        let successful_mints: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM burn_records WHERE is_minted = TRUE",
-            [],
-            |row| row.get(0),
-        )?;
-
-        Ok(Statistics {
-            total_records,
-            total_burned_amount: Decimal::from(total_burned_raw) / Decimal::from(1_000_000),
-            total_minted_amount: Decimal::from(total_minted_raw) / Decimal::from(1_000_000),
-            unique_wallets,
-            pending_mints,
-            successful_mints,
-        })
-    }
-
-    fn row_to_burn_record(&self, row: &Row) -> rusqlite::Result<BurnRecord> {
-        let id: Option<i64> = row.get(0)?;
-        let signature: String = row.get(1)?;
-        let burner: String = row.get(2)?;
-        let amount: i64 = row.get(3)?; // Get raw amount directly
-        let memo: Option<String> = row.get(4)?;
-        let token: Option<String> = row.get(5)?;
-        let timestamp_str: Option<String> = row.get(6)?;
-        let memo_checked: Option<String> = row.get(7)?;
-        let created_at_str: String = row.get(8)?;
-        let is_minted: bool = row.get(9)?;
-        let minted_time_str: Option<String> = row.get(10)?;
-        let minted_signature: Option<String> = row.get(11)?;
-
-        let timestamp = timestamp_str.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        let created_at = chrono::DateTime::parse_from_rfc3339(&created_at_str)
-            .map(|dt| dt.with_timezone(&Utc))
-            .unwrap_or_else(|_| Utc::now());
-
-        let minted_time = minted_time_str.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        Ok(BurnRecord {
-            id,
-            signature,
-            burner,
-            amount: amount as u64, // Convert to u64, but keep original raw value
-            memo,
-            token,
-            timestamp,
-            memo_checked,
-            created_at,
-            is_minted,
-            minted_time,
-            minted_signature,
-        })
+/// File path of a SQLite database URL: `sqlite://path`, `sqlite:path` or a
+/// bare path. Any `?option` suffix is dropped.
+fn resolve_sqlite_path(database_url: &str) -> PathBuf {
+    let path = database_url
+        .strip_prefix("sqlite://")
+        .or_else(|| database_url.strip_prefix("sqlite:"))
+        .unwrap_or(database_url);
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    PathBuf::from(path)
+}
+
+/// Whether a SQLite URL names an in-memory database rather than a file
+fn is_in_memory_url(database_url: &str) -> bool {
+    resolve_sqlite_path(database_url).as_os_str() == ":memory:"
+}
+
+/// Value stored in `mint_attempts.result`
+fn attempt_result(error: Option<&str>) -> &'static str {
+    if error.is_none() { "success" } else { "failure" }
+}
+
+/// Share of mints that succeeded, or None when nothing was attempted yet
+fn success_rate(successful_mints: i64, failed_mints: i64) -> Option<f64> {
+    let attempted = successful_mints + failed_mints;
+    (attempted > 0).then(|| successful_mints as f64 / attempted as f64)
+}
+
+/// Exact sum of raw token units. SQLite's SUM() is a 64-bit integer that
+/// fails once a total passes i64::MAX, so amounts are added up here instead.
+#[derive(Debug, Default, Clone, Copy)]
+struct RawTotal(i128);
+
+impl RawTotal {
+    fn add(&mut self, raw: i64) {
+        self.0 += i128::from(raw);
+    }
+    
+    /// The total in solXEN (6 decimals)
+    fn to_decimal(self) -> Result<Decimal> {
+        Decimal::try_from_i128_with_scale(self.0, 6)
+            .map_err(|e| anyhow::anyhow!("Total of {} raw units does not fit a decimal: {}", self.0, e))
+    }
+}
+
+/// Raw-unit total PostgreSQL summed as NUMERIC and returned as text, in solXEN
+fn parse_raw_total(total: &str) -> Result<Decimal> {
+    let raw = Decimal::from_str(total)
+        .map_err(|e| anyhow::anyhow!("Invalid total {:?} from the database: {}", total, e))?;
+    Ok(raw / Decimal::from(1_000_000))
+}
+
+fn parse_timestamp(value: Option<String>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MemoCheck, NewBurnRecord};
+    use chrono::TimeZone;
+    
+    fn burn(signature: &str, burner: &str, amount: u64) -> NewBurnRecord {
+        NewBurnRecord {
+            signature: signature.to_string(),
+            burner: burner.to_string(),
+            amount,
+            memo: None,
+            token: None,
+            timestamp: None,
+            memo_checked: MemoCheck::Unchecked,
+            created_at: Utc::now(),
+        }
+    }
+    
+    /// An in-memory database holding `burns` as (signature, burner, amount,
+    /// minted amount); a burn with a minted amount is marked minted
+    async fn seed(burns: &[(&str, &str, u64, Option<u64>)]) -> Database {
+        let db = Database::new_in_memory().await.unwrap();
+        for &(signature, burner, amount, minted_amount) in burns {
+            db.insert_burn_record(&burn(signature, burner, amount)).await.unwrap();
+            if let Some(minted_amount) = minted_amount {
+                let mint_signature = format!("mint-{}", signature);
+                db.mark_as_minted(signature, &mint_signature, Some(minted_amount), Some(5_000)).await.unwrap();
+            }
+        }
+        db
+    }
+    
+    #[test]
+    fn backend_is_chosen_by_url_scheme() {
+        for url in ["postgres://user@localhost/solxen", "postgresql://localhost:5432/solxen"] {
+            assert!(Database::is_postgres_url(url), "{}", url);
+            assert_eq!(Database::sqlite_file(url), None);
+            assert_eq!(Database::default_lock_path(url), Some(PathBuf::from("x1-solxen.lock")));
+        }
+        for url in ["sqlite:database/burns.db", "sqlite://database/burns.db", "database/burns.db", "postgres.db"] {
+            assert!(!Database::is_postgres_url(url), "{}", url);
+            assert_eq!(Database::sqlite_file(url), Some(PathBuf::from(url.trim_start_matches("sqlite://").trim_start_matches("sqlite:"))));
+            assert_eq!(Database::default_lock_path(url).unwrap().extension().unwrap(), "lock");
+        }
+        assert!(!Database::is_postgres_url("sqlite::memory:"));
+        assert_eq!(Database::sqlite_file("sqlite::memory:"), None);
+        assert_eq!(Database::default_lock_path("sqlite::memory:"), None);
+    }
+    
+    #[tokio::test]
+    async fn aggregates_of_an_empty_database() {
+        let db = Database::new_in_memory().await.unwrap();
+        
+        let stats = db.get_statistics().await.unwrap();
+        assert_eq!(stats.total_records, 0);
+        assert_eq!(stats.total_burned_amount, Decimal::ZERO);
+        assert_eq!(stats.total_minted_amount, Decimal::ZERO);
+        assert_eq!(stats.unique_wallets, 0);
+        assert_eq!(stats.pending_mints, 0);
+        assert_eq!(stats.successful_mints, 0);
+        assert_eq!(stats.failed_mints, 0);
+        assert_eq!(stats.total_fees_lamports, 0);
+        assert_eq!(stats.success_rate, None);
+        
+        assert!(db.get_wallet_summaries().await.unwrap().is_empty());
+        assert!(db.get_wallet_summary("wallet-a").await.unwrap().is_none());
+    }
+    
+    #[tokio::test]
+    async fn statistics_of_seeded_records() {
+        let db = seed(&[
+            ("burn-1", "wallet-a", 1_500_000, Some(3_000_000)),
+            ("burn-2", "wallet-a", 250_000, None),
+            ("burn-3", "wallet-b", 10_000_000, Some(10_000_000)),
+            ("burn-4", "wallet-c", 1, None),
+        ]).await;
+        db.record_mint_attempt("burn-2", None, Some("blockhash expired"), None).await.unwrap();
+        
+        let stats = db.get_statistics().await.unwrap();
+        assert_eq!(stats.total_records, 4);
+        assert_eq!(stats.total_burned_amount, Decimal::from_str("11.750001").unwrap());
+        assert_eq!(stats.total_minted_amount, Decimal::from(13));
+        assert_eq!(stats.unique_wallets, 3);
+        assert_eq!(stats.pending_mints, 2);
+        assert_eq!(stats.successful_mints, 2);
+        assert_eq!(stats.failed_mints, 1);
+        assert_eq!(stats.total_fees_lamports, 10_000);
+        assert_eq!(stats.success_rate, Some(2.0 / 3.0));
+    }
+    
+    #[tokio::test]
+    async fn wallet_summaries_split_minted_and_unminted() {
+        let db = seed(&[
+            ("burn-1", "wallet-a", 1_500_000, Some(3_000_000)),
+            ("burn-2", "wallet-a", 250_000, None),
+            ("burn-3", "wallet-b", 10_000_000, Some(10_000_000)),
+            ("burn-4", "wallet-c", 1, None),
+        ]).await;
+        
+        // total_burned covers the unminted burns only, and ranks the wallets
+        let summaries = db.get_wallet_summaries().await.unwrap();
+        let rows: Vec<_> = summaries
+            .iter()
+            .map(|s| (s.wallet_address.as_str(), s.total_burned, s.total_minted, s.burn_count, s.mint_count))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("wallet-a", Decimal::from_str("0.25").unwrap(), Decimal::from(3), 2, 1),
+                ("wallet-c", Decimal::from_str("0.000001").unwrap(), Decimal::ZERO, 1, 0),
+                ("wallet-b", Decimal::ZERO, Decimal::from(10), 1, 1),
+            ]
+        );
+        
+        let wallet = db.get_wallet_summary("wallet-a").await.unwrap().unwrap();
+        assert_eq!(wallet.total_burned, Decimal::from_str("0.25").unwrap());
+        assert_eq!(wallet.total_minted, Decimal::from(3));
+        assert!(wallet.last_mint.is_some());
+    }
+    
+    #[tokio::test]
+    async fn failed_records_sort_after_fresh_ones() {
+        let db = Database::new_in_memory().await.unwrap();
+        let day = |day: u32| Some(Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap());
+        for (signature, timestamp) in [("stuck", day(1)), ("flaky", day(2)), ("fresh-old", day(3)), ("fresh-new", day(4))] {
+            db.insert_burn_record(&NewBurnRecord { timestamp, ..burn(signature, "wallet-a", 1_000_000) }).await.unwrap();
+        }
+        for _ in 0..3 {
+            db.record_mint_attempt("stuck", None, Some("custom program error: 0x1"), None).await.unwrap();
+        }
+        db.record_mint_attempt("flaky", None, Some("blockhash not found"), None).await.unwrap();
+        // A successful attempt whose mark_as_minted never ran isn't a failure
+        db.record_mint_attempt("fresh-new", Some("mint-1"), None, None).await.unwrap();
+        
+        let order = |records: Vec<BurnRecord>| records.into_iter().map(|record| record.signature).collect::<Vec<_>>();
+        let expected = ["fresh-old", "fresh-new", "flaky", "stuck"];
+        assert_eq!(order(db.get_pending_mints(0).await.unwrap()), expected);
+        assert_eq!(order(db.get_pending_mints_for("wallet-a", 0).await.unwrap()), expected);
+    }
+    
+    #[tokio::test]
+    async fn totals_past_i64_max_are_exact() {
+        let db = Database::new_in_memory().await.unwrap();
+        let max = i64::MAX as u64;
+        db.insert_burn_record(&burn("burn-1", "wallet-a", max)).await.unwrap();
+        db.insert_burn_record(&burn("burn-2", "wallet-a", max - 1)).await.unwrap();
+        db.insert_burn_record(&burn("burn-3", "wallet-a", 7)).await.unwrap();
+        db.insert_burn_record(&burn("burn-4", "wallet-b", max)).await.unwrap();
+        db.mark_as_minted("burn-4", "mint-4", Some(max), None).await.unwrap();
+        
+        let raw = |total: i128| Decimal::from_i128_with_scale(total, 6);
+        let max = i128::from(i64::MAX);
+        
+        let stats = db.get_statistics().await.unwrap();
+        assert_eq!(stats.total_burned_amount, raw(3 * max - 1 + 7));
+        assert_eq!(stats.total_minted_amount, raw(max));
+        
+        let wallet = db.get_wallet_summary("wallet-a").await.unwrap().unwrap();
+        assert_eq!(wallet.total_burned, raw(2 * max - 1 + 7));
+        assert_eq!(wallet.total_minted, Decimal::ZERO);
+        
+        let summaries = db.get_wallet_summaries().await.unwrap();
+        assert_eq!(summaries[0].wallet_address, "wallet-a");
+        assert_eq!(summaries[0].total_burned, raw(2 * max - 1 + 7));
+    }
+    
+    #[tokio::test]
+    async fn database_from_before_schema_versions_upgrades_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.db");
+        
+        // The table as create_destination_table made it, before schema_version
+        let old = rusqlite::Connection::open(&path).unwrap();
+        old.execute_batch(
+            "CREATE TABLE burn_records (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 signature TEXT UNIQUE NOT NULL,
+                 burner TEXT NOT NULL,
+                 amount DECIMAL(20,6) NOT NULL,
+                 memo TEXT,
+                 token TEXT,
+                 timestamp DATETIME,
+                 memo_checked CHAR(1),
+                 created_at DATETIME NOT NULL,
+                 is_minted BOOLEAN DEFAULT FALSE NOT NULL,
+                 minted_time DATETIME,
+                 minted_signature TEXT
+             );
+             INSERT INTO burn_records (signature, burner, amount, memo_checked, created_at, is_minted, minted_time, minted_signature)
+             VALUES ('burn-1', 'wallet-a', 500000000, 'yes', '2024-01-01T00:00:00+00:00', TRUE, '2024-01-02T00:00:00+00:00', 'mint-1');
+             INSERT INTO burn_records (signature, burner, amount, memo_checked, created_at)
+             VALUES ('burn-2', 'wallet-a', 700000000, NULL, '2024-01-03T00:00:00+00:00');",
+        ).unwrap();
+        drop(old);
+        
+        let mut config = crate::config::Config::load().unwrap();
+        config.database_url = format!("sqlite:{}", path.display());
+        let db = Database::new(&config).await.unwrap();
+        
+        let records = db.get_records_for_burner("wallet-a").await.unwrap();
+        let mut records: Vec<_> = records.iter().map(|record| {
+            (record.signature.as_str(), record.amount, record.is_minted, record.minted_amount, record.memo_checked)
+        }).collect();
+        records.sort_by_key(|record| record.0);
+        assert_eq!(records, [
+            ("burn-1", 500_000_000, true, Some(500_000_000), MemoCheck::Verified),
+            ("burn-2", 700_000_000, false, None, MemoCheck::Unchecked),
+        ]);
+        // The old rows still count, and new columns take writes
+        assert_eq!(db.get_statistics().await.unwrap().total_records, 2);
+        db.mark_as_minted("burn-2", "mint-2", Some(1), Some(5_000)).await.unwrap();
+        db.log_pending_mint("burn-3", "mint-3", "blockhash", 1).await.unwrap();
+        drop(db);
+        
+        let upgraded = rusqlite::Connection::open(&path).unwrap();
+        let columns = upgraded
+            .prepare("SELECT name FROM pragma_table_info('burn_records')")
+            .unwrap()
+            .query_map([], |row| row.get::<_, String>(0))
+            .unwrap()
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .unwrap();
+        assert!(columns.iter().any(|column| column == "fee_lamports"), "{:?}", columns);
+        assert!(columns.iter().any(|column| column == "minted_amount"), "{:?}", columns);
+        let version: i64 = upgraded.query_row("SELECT MAX(version) FROM schema_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, sqlite::MIGRATIONS.len() as i64);
+    }
+    
+    /// Whether a larger prepared statement cache than sqlx's default (100)
+    /// speeds up the hot queries. Run with
+    /// `cargo test statement_cache_capacity_timing -- --ignored --nocapture`.
+    #[tokio::test]
+    #[ignore]
+    async fn statement_cache_capacity_timing() {
+        const RECORDS: usize = 500;
+        const CALLS: u32 = 5_000;
+        for capacity in [100, 256] {
+            let db = Database { store: Arc::new(SqliteStore::connect_in_memory_with_cache(capacity).await.unwrap()) };
+            for index in 0..RECORDS {
+                let signature = format!("burn-{}", index);
+                db.insert_burn_record(&burn(&signature, &format!("wallet-{}", index % 50), 1_000_000)).await.unwrap();
+                if index % 2 == 0 {
+                    db.mark_as_minted(&signature, &format!("mint-{}", index), Some(1_000_000), Some(5_000)).await.unwrap();
+                }
+            }
+            
+            let started = std::time::Instant::now();
+            for _ in 0..CALLS {
+                db.get_statistics().await.unwrap();
+            }
+            println!("capacity {}: {:?} per get_statistics call", capacity, started.elapsed() / CALLS);
+        }
     }
 }
@@ -0,0 +1,526 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::info;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::Executor;
+
+use super::{
+    attempt_result, parse_raw_total, success_rate, BurnRecordRow, BurnStore, FailedMintRow, MintAttemptRow,
+    WalletSummaryRow,
+};
+use crate::metrics::METRICS;
+use crate::types::{
+    BurnRecord, FailedMint, MemoCheckCounts, MintAttempt, NewBurnRecord, PendingMintLogEntry, RecordFilter,
+    Statistics, WalletOrder, WalletSummary,
+};
+
+// WalletSummaryRow plus the exact NUMERIC totals as text
+#[derive(sqlx::FromRow)]
+struct PgWalletSummaryRow {
+    #[sqlx(flatten)]
+    summary: WalletSummaryRow,
+    total_burned_raw: String,
+    total_minted_raw: String,
+}
+
+impl PgWalletSummaryRow {
+    fn into_summary(self) -> Result<WalletSummary> {
+        let total_burned = parse_raw_total(&self.total_burned_raw)?;
+        let total_minted = parse_raw_total(&self.total_minted_raw)?;
+        Ok(self.summary.into_summary(total_burned, total_minted))
+    }
+}
+
+/// Ordered schema migrations, versioned independently of the SQLite ones
+const MIGRATIONS: &[&str] = &[
+    include_str!("../../migrations/postgres/0001_create_schema.sql"),
+    include_str!("../../migrations/postgres/0002_create_migration_state.sql"),
+    include_str!("../../migrations/postgres/0003_add_minted_amount.sql"),
+    include_str!("../../migrations/postgres/0004_index_minted_time.sql"),
+    include_str!("../../migrations/postgres/0005_normalize_memo_checked.sql"),
+];
+
+const BURN_RECORD_COLUMNS: &str = "id, signature, burner, amount, memo, token, timestamp, memo_checked, \
+     created_at, is_minted, minted_time, minted_signature, minted_amount";
+
+/// PostgreSQL backend for deployments with several writers. The compile-time
+/// checked macros only cover one driver, so these queries are checked at runtime.
+pub struct PostgresStore {
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    pub async fn connect(database_url: &str) -> Result<Self> {
+        let pool = PgPoolOptions::new().connect(database_url).await?;
+
+        let store = PostgresStore { pool };
+        store.run_migrations().await?;
+        Ok(store)
+    }
+
+    pub async fn probe(database_url: &str) -> Result<i64> {
+        let pool = PgPoolOptions::new().max_connections(1).connect(database_url).await?;
+        let count = sqlx::query_scalar("SELECT COUNT(*) FROM burn_records").fetch_one(&pool).await?;
+        pool.close().await;
+        Ok(count)
+    }
+
+    async fn run_migrations(&self) -> Result<()> {
+        self.pool
+            .execute(
+                "CREATE TABLE IF NOT EXISTS schema_version (
+                    version BIGINT PRIMARY KEY,
+                    applied_at TEXT NOT NULL
+                )",
+            )
+            .await?;
+
+        let current: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+            .fetch_one(&self.pool)
+            .await?;
+
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            let version = index as i64 + 1;
+            if version <= current {
+                continue;
+            }
+
+            let mut tx = self.pool.begin().await?;
+            tx.execute(*migration).await?;
+            sqlx::query("INSERT INTO schema_version (version, applied_at) VALUES ($1, $2)")
+                .bind(version)
+                .bind(Utc::now().to_rfc3339())
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await?;
+
+            info!("Applied database migration to schema version {}", version);
+        }
+
+        Ok(())
+    }
+
+    /// Unminted burns of at least `min_amount`, optionally of one burner only
+    async fn pending_mints(&self, burner: Option<&str>, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        let rows: Vec<BurnRecordRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM burn_records
+             WHERE NOT is_minted AND amount >= $1 AND ($2::TEXT IS NULL OR burner = $2)
+             ORDER BY (SELECT COUNT(*) FROM mint_attempts a
+                       WHERE a.burn_signature = burn_records.signature AND a.result = 'failure') ASC,
+                      timestamp ASC NULLS FIRST",
+            BURN_RECORD_COLUMNS
+        ))
+        .bind(min_amount as i64)
+        .bind(burner)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    /// Per-wallet totals over every record, or over those created or minted
+    /// after `since`, ranked by `order` and cut off after `limit` wallets
+    async fn wallet_summaries(
+        &self,
+        since: Option<&str>,
+        order: WalletOrder,
+        limit: Option<i64>,
+    ) -> Result<Vec<WalletSummary>> {
+        // Ranked by the NUMERIC sums, which can't overflow
+        let burned = "SUM(CASE WHEN NOT is_minted THEN amount ELSE 0 END) DESC";
+        let order_by = match order {
+            WalletOrder::Burned => burned.to_string(),
+            WalletOrder::Minted => format!("SUM(CASE WHEN is_minted THEN minted_amount ELSE 0 END) DESC, {}", burned),
+            WalletOrder::BurnCount => format!("burn_count DESC, {}", burned),
+            WalletOrder::LastMint => format!("last_mint DESC NULLS LAST, {}", burned),
+        };
+        // SUM over BIGINT yields NUMERIC in Postgres, which can't overflow; it
+        // comes back as text so no precision is lost on the way
+        let rows: Vec<PgWalletSummaryRow> = sqlx::query_as(&format!(
+            "SELECT
+                burner,
+                SUM(CASE WHEN NOT is_minted THEN amount ELSE 0 END)::TEXT as total_burned_raw,
+                SUM(CASE WHEN is_minted THEN minted_amount ELSE 0 END)::TEXT as total_minted_raw,
+                COUNT(*) as burn_count,
+                COUNT(*) FILTER (WHERE is_minted) as mint_count,
+                MIN(timestamp) as first_burn,
+                MAX(minted_time) as last_mint
+            FROM burn_records
+            WHERE $1::TEXT IS NULL OR created_at > $1 OR minted_time > $1
+            GROUP BY burner
+            ORDER BY {}
+            LIMIT $2",
+            order_by
+        ))
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(PgWalletSummaryRow::into_summary).collect()
+    }
+
+    /// Statistics over every record, or over those created or minted after `since`
+    async fn statistics(&self, since: Option<&str>) -> Result<Statistics> {
+        let (
+            total_records,
+            total_burned_raw,
+            total_minted_raw,
+            unique_wallets,
+            pending_mints,
+            successful_mints,
+            failed_mints,
+            total_fees_lamports,
+            memo_verified,
+            memo_rejected,
+        ): (i64, String, String, i64, i64, i64, i64, i64, i64, i64) = sqlx::query_as(
+            "SELECT
+                COUNT(*),
+                COALESCE(SUM(amount), 0)::TEXT,
+                COALESCE(SUM(minted_amount) FILTER (WHERE is_minted), 0)::TEXT,
+                COUNT(DISTINCT burner),
+                COUNT(*) FILTER (WHERE NOT is_minted),
+                COUNT(*) FILTER (WHERE is_minted),
+                (SELECT COUNT(DISTINCT b.signature)
+                 FROM burn_records b
+                 JOIN mint_attempts a ON a.burn_signature = b.signature AND a.result = 'failure'
+                 WHERE NOT b.is_minted AND ($1::TEXT IS NULL OR b.created_at > $1 OR b.minted_time > $1)),
+                COALESCE(SUM(fee_lamports) FILTER (WHERE is_minted), 0)::BIGINT,
+                COUNT(*) FILTER (WHERE memo_checked = 'Y'),
+                COUNT(*) FILTER (WHERE memo_checked = 'N')
+            FROM burn_records
+            WHERE $1::TEXT IS NULL OR created_at > $1 OR minted_time > $1",
+        )
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let stats = Statistics {
+            total_records,
+            total_burned_amount: parse_raw_total(&total_burned_raw)?,
+            total_minted_amount: parse_raw_total(&total_minted_raw)?,
+            unique_wallets,
+            pending_mints,
+            successful_mints,
+            failed_mints,
+            total_fees_lamports: total_fees_lamports as u64,
+            success_rate: success_rate(successful_mints, failed_mints),
+            memo_checks: MemoCheckCounts {
+                verified: memo_verified,
+                rejected: memo_rejected,
+                unchecked: total_records - memo_verified - memo_rejected,
+            },
+        };
+        // The gauges track the whole database, not a report window
+        if since.is_none() {
+            METRICS.observe_statistics(&stats);
+        }
+
+        Ok(stats)
+    }
+}
+
+#[async_trait]
+impl BurnStore for PostgresStore {
+    async fn signature_exists(&self, signature: &str) -> Result<bool> {
+        let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM burn_records WHERE signature = $1)")
+            .bind(signature)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(exists)
+    }
+
+    async fn insert_burn_record(&self, record: &NewBurnRecord) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO burn_records (
+                signature, burner, amount, memo, token, timestamp, memo_checked, created_at, is_minted
+             ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, FALSE)",
+        )
+        .bind(&record.signature)
+        .bind(&record.burner)
+        .bind(record.amount as i64)
+        .bind(&record.memo)
+        .bind(&record.token)
+        .bind(record.timestamp.map(|t| t.to_rfc3339()))
+        .bind(record.memo_checked.as_column())
+        .bind(record.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_pending_mints(&self, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        self.pending_mints(None, min_amount).await
+    }
+
+    async fn get_pending_mints_for(&self, burner: &str, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        self.pending_mints(Some(burner), min_amount).await
+    }
+
+    async fn get_failed_mints(&self, burner: Option<&str>) -> Result<Vec<FailedMint>> {
+        let rows: Vec<FailedMintRow> = sqlx::query_as(
+            "SELECT b.id, b.signature, b.burner, b.amount, b.memo, b.token, b.timestamp, b.memo_checked,
+                    b.created_at, b.is_minted, b.minted_time, b.minted_signature, b.minted_amount,
+                    COUNT(a.id) as failed_attempts
+             FROM burn_records b
+             JOIN mint_attempts a ON a.burn_signature = b.signature AND a.result = 'failure'
+             WHERE NOT b.is_minted AND ($1::TEXT IS NULL OR b.burner = $1)
+             GROUP BY b.id
+             ORDER BY b.timestamp ASC NULLS FIRST",
+        )
+        .bind(burner)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(FailedMint::from).collect())
+    }
+
+    async fn mark_as_minted(
+        &self,
+        signature: &str,
+        minted_signature: &str,
+        minted_amount: Option<u64>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "UPDATE burn_records SET is_minted = TRUE, minted_time = $1, minted_signature = $2,
+                minted_amount = COALESCE($3, amount), fee_lamports = $4
+             WHERE signature = $5",
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(minted_signature)
+        .bind(minted_amount.map(|amount| amount as i64))
+        .bind(fee_lamports.map(|fee| fee as i64))
+        .bind(signature)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM pending_mint_log WHERE burn_signature = $1")
+            .bind(signature)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn reset_mint(&self, signature: &str) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE burn_records SET is_minted = FALSE, minted_time = NULL, minted_signature = NULL,
+                minted_amount = NULL, fee_lamports = NULL
+             WHERE signature = $1 AND is_minted",
+        )
+        .bind(signature)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn log_pending_mint(
+        &self,
+        burn_signature: &str,
+        mint_signature: &str,
+        recent_blockhash: &str,
+        minted_amount: u64,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO pending_mint_log (burn_signature, mint_signature, recent_blockhash, logged_at, minted_amount)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (burn_signature) DO UPDATE SET
+                mint_signature = EXCLUDED.mint_signature,
+                recent_blockhash = EXCLUDED.recent_blockhash,
+                logged_at = EXCLUDED.logged_at,
+                minted_amount = EXCLUDED.minted_amount",
+        )
+        .bind(burn_signature)
+        .bind(mint_signature)
+        .bind(recent_blockhash)
+        .bind(Utc::now().to_rfc3339())
+        .bind(minted_amount as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn clear_pending_mint(&self, burn_signature: &str) -> Result<()> {
+        sqlx::query("DELETE FROM pending_mint_log WHERE burn_signature = $1")
+            .bind(burn_signature)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_pending_mint_log(&self) -> Result<Vec<PendingMintLogEntry>> {
+        let rows: Vec<(String, String, String, Option<i64>)> = sqlx::query_as(
+            "SELECT burn_signature, mint_signature, recent_blockhash, minted_amount
+             FROM pending_mint_log
+             ORDER BY logged_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(burn_signature, mint_signature, recent_blockhash, minted_amount)| PendingMintLogEntry {
+                burn_signature,
+                mint_signature,
+                recent_blockhash,
+                minted_amount: minted_amount.map(|amount| amount as u64),
+            })
+            .collect())
+    }
+
+    async fn record_mint_attempt(
+        &self,
+        burn_signature: &str,
+        tx_signature: Option<&str>,
+        error: Option<&str>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO mint_attempts (burn_signature, attempted_at, result, tx_signature, error, fee_lamports)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(burn_signature)
+        .bind(Utc::now().to_rfc3339())
+        .bind(attempt_result(error))
+        .bind(tx_signature)
+        .bind(error)
+        .bind(fee_lamports.map(|fee| fee as i64))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_attempts_for(&self, burn_signature: &str) -> Result<Vec<MintAttempt>> {
+        let rows: Vec<MintAttemptRow> = sqlx::query_as(
+            "SELECT id, burn_signature, attempted_at, result, tx_signature, error, fee_lamports
+             FROM mint_attempts
+             WHERE burn_signature = $1
+             ORDER BY id ASC",
+        )
+        .bind(burn_signature)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(MintAttempt::from).collect())
+    }
+
+    async fn get_migration_checkpoint(&self, source: &str) -> Result<Option<i64>> {
+        let checkpoint = sqlx::query_scalar("SELECT checkpoint FROM migration_state WHERE source = $1")
+            .bind(source)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(checkpoint)
+    }
+
+    async fn set_migration_checkpoint(&self, source: &str, checkpoint: i64) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO migration_state (source, checkpoint, updated_at) VALUES ($1, $2, $3)
+             ON CONFLICT (source) DO UPDATE SET checkpoint = EXCLUDED.checkpoint, updated_at = EXCLUDED.updated_at",
+        )
+        .bind(source)
+        .bind(checkpoint)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_records_filtered(&self, filter: &RecordFilter, limit: Option<i64>, offset: i64) -> Result<Vec<BurnRecord>> {
+        let (min_amount, max_amount) = filter.amount_range();
+        let rows: Vec<BurnRecordRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM burn_records b
+             WHERE amount BETWEEN $1 AND $2
+               AND ($3::TEXT IS NULL OR created_at > $3 OR minted_time > $3)
+               AND ($4::TEXT IS NULL
+                    OR ($4 = 'minted' AND is_minted)
+                    OR ($4 = 'pending' AND NOT is_minted)
+                    OR ($4 = 'failed' AND NOT is_minted AND EXISTS (
+                        SELECT 1 FROM mint_attempts a WHERE a.burn_signature = b.signature AND a.result = 'failure')))
+               AND ($5::TEXT IS NULL OR burner = $5)
+             ORDER BY COALESCE(timestamp, created_at) DESC, id DESC
+             LIMIT $6 OFFSET $7",
+            BURN_RECORD_COLUMNS
+        ))
+        .bind(min_amount)
+        .bind(max_amount)
+        .bind(filter.since.map(|since| since.to_rfc3339()))
+        .bind(filter.status.map(|status| status.as_str()))
+        .bind(filter.burner.as_deref())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_recent_mints(&self, limit: i64) -> Result<Vec<BurnRecord>> {
+        let rows: Vec<BurnRecordRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM burn_records
+             WHERE is_minted AND minted_time IS NOT NULL
+             ORDER BY minted_time DESC
+             LIMIT $1",
+            BURN_RECORD_COLUMNS
+        ))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_records_for_burner(&self, burner: &str) -> Result<Vec<BurnRecord>> {
+        let rows: Vec<BurnRecordRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM burn_records WHERE burner = $1 ORDER BY COALESCE(timestamp, created_at) DESC",
+            BURN_RECORD_COLUMNS
+        ))
+        .bind(burner)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_wallet_summaries(&self) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(None, WalletOrder::Burned, None).await
+    }
+
+    async fn get_wallet_summaries_by(&self, order: WalletOrder, limit: Option<i64>) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(None, order, limit).await
+    }
+
+    async fn get_wallet_summaries_since(&self, since: DateTime<Utc>) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(Some(&since.to_rfc3339()), WalletOrder::Burned, None).await
+    }
+
+    async fn get_wallet_summary(&self, burner: &str) -> Result<Option<WalletSummary>> {
+        let row: Option<PgWalletSummaryRow> = sqlx::query_as(
+            "SELECT
+                burner,
+                SUM(CASE WHEN NOT is_minted THEN amount ELSE 0 END)::TEXT as total_burned_raw,
+                SUM(CASE WHEN is_minted THEN minted_amount ELSE 0 END)::TEXT as total_minted_raw,
+                COUNT(*) as burn_count,
+                COUNT(*) FILTER (WHERE is_minted) as mint_count,
+                MIN(timestamp) as first_burn,
+                MAX(minted_time) as last_mint
+            FROM burn_records
+            WHERE burner = $1
+            GROUP BY burner",
+        )
+        .bind(burner)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(PgWalletSummaryRow::into_summary).transpose()
+    }
+
+    async fn get_statistics(&self) -> Result<Statistics> {
+        self.statistics(None).await
+    }
+
+    async fn get_statistics_since(&self, since: DateTime<Utc>) -> Result<Statistics> {
+        self.statistics(Some(&since.to_rfc3339())).await
+    }
+}
@@ -0,0 +1,679 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::info;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::Executor;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{
+    attempt_result, resolve_sqlite_path, success_rate, BurnRecordRow, BurnStore, FailedMintRow, MintAttemptRow,
+    RawTotal, WalletSummaryRow,
+};
+use crate::metrics::METRICS;
+use crate::types::{
+    BurnRecord, FailedMint, MemoCheckCounts, MintAttempt, NewBurnRecord, PendingMintLogEntry, RecordFilter,
+    Statistics, WalletOrder, WalletSummary,
+};
+
+/// Ordered schema migrations. The entry at index `i` upgrades the schema to
+/// version `i + 1`; append new steps, never edit or reorder applied ones.
+pub(super) const MIGRATIONS: &[&str] = &[
+    include_str!("../../migrations/sqlite/0001_create_burn_records.sql"),
+    include_str!("../../migrations/sqlite/0002_create_pending_mint_log.sql"),
+    include_str!("../../migrations/sqlite/0003_create_mint_attempts.sql"),
+    include_str!("../../migrations/sqlite/0004_add_fee_lamports.sql"),
+    include_str!("../../migrations/sqlite/0005_create_migration_state.sql"),
+    include_str!("../../migrations/sqlite/0006_add_minted_amount.sql"),
+    include_str!("../../migrations/sqlite/0007_index_minted_time.sql"),
+    include_str!("../../migrations/sqlite/0008_normalize_memo_checked.sql"),
+];
+
+/// SQLite backend; its queries are checked against .sqlx/ at compile time
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    /// Open the database in WAL mode so the report generator and API can read
+    /// while the minter writes; writers wait up to `busy_timeout` for a lock.
+    pub async fn connect(database_url: &str, busy_timeout: Duration) -> Result<Self> {
+        let path = resolve_sqlite_path(database_url);
+
+        // Ensure database directory exists
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let options = SqliteConnectOptions::new()
+            .filename(&path)
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .busy_timeout(busy_timeout);
+        let pool = SqlitePoolOptions::new().connect_with(options).await?;
+
+        let store = SqliteStore { pool };
+        store.run_migrations().await?;
+        Ok(store)
+    }
+
+    /// Open a private in-memory database. Every SQLite connection to
+    /// `:memory:` gets its own empty database, so the pool is pinned to a
+    /// single connection that is never recycled.
+    pub async fn connect_in_memory() -> Result<Self> {
+        Self::connect_in_memory_with(SqliteConnectOptions::from_str("sqlite::memory:")?).await
+    }
+
+    /// In-memory store whose connection keeps `capacity` prepared statements
+    #[cfg(test)]
+    pub(super) async fn connect_in_memory_with_cache(capacity: usize) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str("sqlite::memory:")?.statement_cache_capacity(capacity);
+        Self::connect_in_memory_with(options).await
+    }
+
+    async fn connect_in_memory_with(options: SqliteConnectOptions) -> Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await?;
+
+        let store = SqliteStore { pool };
+        store.run_migrations().await?;
+        Ok(store)
+    }
+
+    pub async fn probe(database_url: &str) -> Result<i64> {
+        let options = SqliteConnectOptions::new()
+            .filename(resolve_sqlite_path(database_url))
+            .read_only(true);
+        let pool = SqlitePoolOptions::new().max_connections(1).connect_with(options).await?;
+        let count = sqlx::query_scalar("SELECT COUNT(*) FROM burn_records").fetch_one(&pool).await?;
+        pool.close().await;
+        Ok(count)
+    }
+
+    /// Bring the schema up to the latest version, applying each pending step
+    /// in its own transaction together with its `schema_version` row.
+    async fn run_migrations(&self) -> Result<()> {
+        self.pool
+            .execute(
+                "CREATE TABLE IF NOT EXISTS schema_version (
+                    version INTEGER PRIMARY KEY,
+                    applied_at DATETIME NOT NULL
+                )",
+            )
+            .await?;
+
+        let current: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+            .fetch_one(&self.pool)
+            .await?;
+
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            let version = index as i64 + 1;
+            if version <= current {
+                continue;
+            }
+
+            let mut tx = self.pool.begin().await?;
+            tx.execute(*migration).await?;
+            sqlx::query("INSERT INTO schema_version (version, applied_at) VALUES (?1, ?2)")
+                .bind(version)
+                .bind(Utc::now().to_rfc3339())
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await?;
+
+            info!("Applied database migration to schema version {}", version);
+        }
+
+        Ok(())
+    }
+
+    /// Unminted burns of at least `min_amount`, optionally of one burner only
+    async fn pending_mints(&self, burner: Option<&str>, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        let min_amount = min_amount as i64;
+        let rows = sqlx::query_as!(
+            BurnRecordRow,
+            r#"SELECT id, signature, burner, amount as "amount!: i64", memo, token,
+                    timestamp as "timestamp: String", memo_checked,
+                    created_at as "created_at!: String", is_minted as "is_minted!: bool",
+                    minted_time as "minted_time: String", minted_signature, minted_amount
+             FROM burn_records
+             WHERE is_minted = FALSE AND amount >= ?1 AND (?2 IS NULL OR burner = ?2)
+             ORDER BY (SELECT COUNT(*) FROM mint_attempts a
+                       WHERE a.burn_signature = burn_records.signature AND a.result = 'failure') ASC,
+                      timestamp ASC"#,
+            min_amount,
+            burner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    /// Per-wallet totals over every record, or over those created or minted
+    /// after `since`, ranked by `order` and cut off after `limit` wallets
+    async fn wallet_summaries(
+        &self,
+        since: Option<&str>,
+        order: WalletOrder,
+        limit: Option<i64>,
+    ) -> Result<Vec<WalletSummary>> {
+        let order = order.as_str();
+        let limit = limit.unwrap_or(-1); // SQLite reads a negative LIMIT as none
+        // Ranked with TOTAL(), SQLite's float sum, which can't overflow like SUM()
+        let rows = sqlx::query_as!(
+            WalletSummaryRow,
+            r#"
+            SELECT
+                burner as "burner!",
+                COUNT(*) as "burn_count!: i64",
+                SUM(CASE WHEN is_minted = TRUE THEN 1 ELSE 0 END) as "mint_count!: i64",
+                MIN(timestamp) as "first_burn: String",
+                MAX(minted_time) as "last_mint: String"
+            FROM burn_records
+            WHERE ?1 IS NULL OR created_at > ?1 OR minted_time > ?1
+            GROUP BY burner
+            ORDER BY
+                CASE ?2
+                    WHEN 'minted' THEN TOTAL(CASE WHEN is_minted = TRUE THEN minted_amount ELSE 0 END)
+                    WHEN 'burn_count' THEN COUNT(*)
+                END DESC,
+                CASE ?2 WHEN 'last_mint' THEN MAX(minted_time) END DESC,
+                TOTAL(CASE WHEN is_minted = FALSE THEN amount ELSE 0 END) DESC
+            LIMIT ?3
+            "#,
+            since,
+            order,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut totals = self.wallet_totals(since, None).await?;
+        rows.into_iter()
+            .map(|row| {
+                let (burned, minted) = totals.remove(&row.burner).unwrap_or_default();
+                Ok(row.into_summary(burned.to_decimal()?, minted.to_decimal()?))
+            })
+            .collect()
+    }
+
+    /// Exact unminted-burned and minted totals per wallet, over the records
+    /// matching `since` and, when given, of one burner only
+    async fn wallet_totals(&self, since: Option<&str>, burner: Option<&str>) -> Result<HashMap<String, (RawTotal, RawTotal)>> {
+        let rows = sqlx::query!(
+            r#"SELECT burner as "burner!", amount as "amount!: i64", is_minted as "is_minted!: bool",
+                    minted_amount as "minted_amount: i64"
+             FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1) AND (?2 IS NULL OR burner = ?2)"#,
+            since,
+            burner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut totals: HashMap<String, (RawTotal, RawTotal)> = HashMap::new();
+        for row in rows {
+            let (burned, minted) = totals.entry(row.burner).or_default();
+            if row.is_minted {
+                minted.add(row.minted_amount.unwrap_or_default());
+            } else {
+                burned.add(row.amount);
+            }
+        }
+        Ok(totals)
+    }
+
+    /// Statistics over every record, or over those created or minted after `since`
+    async fn statistics(&self, since: Option<&str>) -> Result<Statistics> {
+        let total_records = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1)"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let amounts = sqlx::query!(
+            r#"SELECT amount as "amount!: i64", is_minted as "is_minted!: bool", minted_amount as "minted_amount: i64"
+             FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1)"#,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let mut total_burned = RawTotal::default();
+        let mut total_minted = RawTotal::default();
+        for row in &amounts {
+            total_burned.add(row.amount);
+            if row.is_minted {
+                total_minted.add(row.minted_amount.unwrap_or_default());
+            }
+        }
+
+        let unique_wallets = sqlx::query_scalar!(
+            r#"SELECT COUNT(DISTINCT burner) as "count!: i64" FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1)"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let pending_mints = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1) AND is_minted = FALSE"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let successful_mints = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1) AND is_minted = TRUE"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let failed_mints = sqlx::query_scalar!(
+            r#"SELECT COUNT(DISTINCT b.signature) as "count!: i64"
+             FROM burn_records b
+             JOIN mint_attempts a ON a.burn_signature = b.signature AND a.result = 'failure'
+             WHERE b.is_minted = FALSE AND (?1 IS NULL OR b.created_at > ?1 OR b.minted_time > ?1)"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let total_fees_lamports = sqlx::query_scalar!(
+            r#"SELECT COALESCE(SUM(fee_lamports), 0) as "total!: i64" FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1) AND is_minted = TRUE"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        // memo_checked holds 'Y' or 'N' once normalized; anything else is unchecked
+        let memo_checks = sqlx::query!(
+            r#"SELECT COALESCE(SUM(memo_checked = 'Y'), 0) as "verified!: i64",
+                    COALESCE(SUM(memo_checked = 'N'), 0) as "rejected!: i64"
+             FROM burn_records
+             WHERE (?1 IS NULL OR created_at > ?1 OR minted_time > ?1)"#,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let stats = Statistics {
+            total_records,
+            total_burned_amount: total_burned.to_decimal()?,
+            total_minted_amount: total_minted.to_decimal()?,
+            unique_wallets,
+            pending_mints,
+            successful_mints,
+            failed_mints,
+            total_fees_lamports: total_fees_lamports as u64,
+            success_rate: success_rate(successful_mints, failed_mints),
+            memo_checks: MemoCheckCounts {
+                verified: memo_checks.verified,
+                rejected: memo_checks.rejected,
+                unchecked: total_records - memo_checks.verified - memo_checks.rejected,
+            },
+        };
+        // The gauges track the whole database, not a report window
+        if since.is_none() {
+            METRICS.observe_statistics(&stats);
+        }
+
+        Ok(stats)
+    }
+}
+
+#[async_trait]
+impl BurnStore for SqliteStore {
+    async fn signature_exists(&self, signature: &str) -> Result<bool> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM burn_records WHERE signature = ?1"#,
+            signature
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count > 0)
+    }
+
+    async fn insert_burn_record(&self, record: &NewBurnRecord) -> Result<()> {
+        let amount = record.amount as i64;
+        let timestamp = record.timestamp.map(|t| t.to_rfc3339());
+        let created_at = record.created_at.to_rfc3339();
+        let memo_checked = record.memo_checked.as_column();
+        sqlx::query!(
+            "INSERT INTO burn_records (
+                signature, burner, amount, memo, token, timestamp, memo_checked, created_at, is_minted
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, FALSE)",
+            record.signature,
+            record.burner,
+            amount,
+            record.memo,
+            record.token,
+            timestamp,
+            memo_checked,
+            created_at
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_pending_mints(&self, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        self.pending_mints(None, min_amount).await
+    }
+
+    async fn get_pending_mints_for(&self, burner: &str, min_amount: u64) -> Result<Vec<BurnRecord>> {
+        self.pending_mints(Some(burner), min_amount).await
+    }
+
+    async fn get_failed_mints(&self, burner: Option<&str>) -> Result<Vec<FailedMint>> {
+        let rows = sqlx::query_as!(
+            FailedMintRow,
+            r#"SELECT b.id, b.signature, b.burner, b.amount as "amount!: i64", b.memo, b.token,
+                    b.timestamp as "timestamp: String", b.memo_checked,
+                    b.created_at as "created_at!: String", b.is_minted as "is_minted!: bool",
+                    b.minted_time as "minted_time: String", b.minted_signature, b.minted_amount,
+                    COUNT(a.id) as "failed_attempts!: i64"
+             FROM burn_records b
+             JOIN mint_attempts a ON a.burn_signature = b.signature AND a.result = 'failure'
+             WHERE b.is_minted = FALSE AND (?1 IS NULL OR b.burner = ?1)
+             GROUP BY b.id
+             ORDER BY b.timestamp ASC"#,
+            burner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(FailedMint::from).collect())
+    }
+
+    async fn mark_as_minted(
+        &self,
+        signature: &str,
+        minted_signature: &str,
+        minted_amount: Option<u64>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        let minted_time = Utc::now().to_rfc3339();
+        let minted_amount = minted_amount.map(|amount| amount as i64);
+        let fee_lamports = fee_lamports.map(|fee| fee as i64);
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!(
+            "UPDATE burn_records SET is_minted = TRUE, minted_time = ?1, minted_signature = ?2,
+                minted_amount = COALESCE(?3, amount), fee_lamports = ?4
+             WHERE signature = ?5",
+            minted_time,
+            minted_signature,
+            minted_amount,
+            fee_lamports,
+            signature
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!("DELETE FROM pending_mint_log WHERE burn_signature = ?1", signature)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn reset_mint(&self, signature: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            "UPDATE burn_records SET is_minted = FALSE, minted_time = NULL, minted_signature = NULL,
+                minted_amount = NULL, fee_lamports = NULL
+             WHERE signature = ?1 AND is_minted = TRUE",
+            signature
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn log_pending_mint(
+        &self,
+        burn_signature: &str,
+        mint_signature: &str,
+        recent_blockhash: &str,
+        minted_amount: u64,
+    ) -> Result<()> {
+        let logged_at = Utc::now().to_rfc3339();
+        let minted_amount = minted_amount as i64;
+        sqlx::query!(
+            "INSERT OR REPLACE INTO pending_mint_log (
+                burn_signature, mint_signature, recent_blockhash, logged_at, minted_amount
+             ) VALUES (?1, ?2, ?3, ?4, ?5)",
+            burn_signature,
+            mint_signature,
+            recent_blockhash,
+            logged_at,
+            minted_amount
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn clear_pending_mint(&self, burn_signature: &str) -> Result<()> {
+        sqlx::query!("DELETE FROM pending_mint_log WHERE burn_signature = ?1", burn_signature)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn get_pending_mint_log(&self) -> Result<Vec<PendingMintLogEntry>> {
+        let rows = sqlx::query!(
+            "SELECT burn_signature, mint_signature, recent_blockhash, minted_amount
+             FROM pending_mint_log
+             ORDER BY logged_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PendingMintLogEntry {
+                burn_signature: row.burn_signature,
+                mint_signature: row.mint_signature,
+                recent_blockhash: row.recent_blockhash,
+                minted_amount: row.minted_amount.map(|amount| amount as u64),
+            })
+            .collect())
+    }
+
+    async fn record_mint_attempt(
+        &self,
+        burn_signature: &str,
+        tx_signature: Option<&str>,
+        error: Option<&str>,
+        fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        let attempted_at = Utc::now().to_rfc3339();
+        let result = attempt_result(error);
+        let fee_lamports = fee_lamports.map(|fee| fee as i64);
+        sqlx::query!(
+            "INSERT INTO mint_attempts (burn_signature, attempted_at, result, tx_signature, error, fee_lamports)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            burn_signature,
+            attempted_at,
+            result,
+            tx_signature,
+            error,
+            fee_lamports
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_attempts_for(&self, burn_signature: &str) -> Result<Vec<MintAttempt>> {
+        let rows = sqlx::query_as!(
+            MintAttemptRow,
+            r#"SELECT id as "id!: i64", burn_signature, attempted_at as "attempted_at!: String",
+                    result, tx_signature, error, fee_lamports
+             FROM mint_attempts
+             WHERE burn_signature = ?1
+             ORDER BY id ASC"#,
+            burn_signature
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(MintAttempt::from).collect())
+    }
+
+    async fn get_migration_checkpoint(&self, source: &str) -> Result<Option<i64>> {
+        let checkpoint = sqlx::query_scalar!(
+            r#"SELECT checkpoint as "checkpoint!: i64" FROM migration_state WHERE source = ?1"#,
+            source
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(checkpoint)
+    }
+
+    async fn set_migration_checkpoint(&self, source: &str, checkpoint: i64) -> Result<()> {
+        let updated_at = Utc::now().to_rfc3339();
+        sqlx::query!(
+            "INSERT OR REPLACE INTO migration_state (source, checkpoint, updated_at) VALUES (?1, ?2, ?3)",
+            source,
+            checkpoint,
+            updated_at
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_records_filtered(&self, filter: &RecordFilter, limit: Option<i64>, offset: i64) -> Result<Vec<BurnRecord>> {
+        let (min_amount, max_amount) = filter.amount_range();
+        let since = filter.since.map(|since| since.to_rfc3339());
+        let status = filter.status.map(|status| status.as_str());
+        let limit = limit.unwrap_or(-1); // SQLite reads a negative LIMIT as none
+        // The amount range is always bound so idx_amount can serve it
+        let rows = sqlx::query_as!(
+            BurnRecordRow,
+            r#"SELECT id, signature, burner, amount as "amount!: i64", memo, token,
+                    timestamp as "timestamp: String", memo_checked,
+                    created_at as "created_at!: String", is_minted as "is_minted!: bool",
+                    minted_time as "minted_time: String", minted_signature, minted_amount
+             FROM burn_records b
+             WHERE amount BETWEEN ?1 AND ?2
+               AND (?3 IS NULL OR created_at > ?3 OR minted_time > ?3)
+               AND (?4 IS NULL
+                    OR (?4 = 'minted' AND is_minted = TRUE)
+                    OR (?4 = 'pending' AND is_minted = FALSE)
+                    OR (?4 = 'failed' AND is_minted = FALSE AND EXISTS (
+                        SELECT 1 FROM mint_attempts a WHERE a.burn_signature = b.signature AND a.result = 'failure')))
+               AND (?5 IS NULL OR burner = ?5)
+             ORDER BY COALESCE(timestamp, created_at) DESC, id DESC
+             LIMIT ?6 OFFSET ?7"#,
+            min_amount,
+            max_amount,
+            since,
+            status,
+            filter.burner,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_recent_mints(&self, limit: i64) -> Result<Vec<BurnRecord>> {
+        let rows = sqlx::query_as!(
+            BurnRecordRow,
+            r#"SELECT id, signature, burner, amount as "amount!: i64", memo, token,
+                    timestamp as "timestamp: String", memo_checked,
+                    created_at as "created_at!: String", is_minted as "is_minted!: bool",
+                    minted_time as "minted_time: String", minted_signature, minted_amount
+             FROM burn_records
+             WHERE is_minted = TRUE AND minted_time IS NOT NULL
+             ORDER BY minted_time DESC
+             LIMIT ?1"#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_records_for_burner(&self, burner: &str) -> Result<Vec<BurnRecord>> {
+        let rows = sqlx::query_as!(
+            BurnRecordRow,
+            r#"SELECT id, signature, burner, amount as "amount!: i64", memo, token,
+                    timestamp as "timestamp: String", memo_checked,
+                    created_at as "created_at!: String", is_minted as "is_minted!: bool",
+                    minted_time as "minted_time: String", minted_signature, minted_amount
+             FROM burn_records
+             WHERE burner = ?1
+             ORDER BY COALESCE(timestamp, created_at) DESC"#,
+            burner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BurnRecord::from).collect())
+    }
+
+    async fn get_wallet_summaries(&self) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(None, WalletOrder::Burned, None).await
+    }
+
+    async fn get_wallet_summaries_by(&self, order: WalletOrder, limit: Option<i64>) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(None, order, limit).await
+    }
+
+    async fn get_wallet_summaries_since(&self, since: DateTime<Utc>) -> Result<Vec<WalletSummary>> {
+        self.wallet_summaries(Some(&since.to_rfc3339()), WalletOrder::Burned, None).await
+    }
+
+    async fn get_wallet_summary(&self, burner: &str) -> Result<Option<WalletSummary>> {
+        let row = sqlx::query_as!(
+            WalletSummaryRow,
+            r#"
+            SELECT
+                burner as "burner!",
+                COUNT(*) as "burn_count!: i64",
+                SUM(CASE WHEN is_minted = TRUE THEN 1 ELSE 0 END) as "mint_count!: i64",
+                MIN(timestamp) as "first_burn: String",
+                MAX(minted_time) as "last_mint: String"
+            FROM burn_records
+            WHERE burner = ?1
+            GROUP BY burner
+            "#,
+            burner
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let (burned, minted) = self.wallet_totals(None, Some(burner)).await?.remove(burner).unwrap_or_default();
+        Ok(Some(row.into_summary(burned.to_decimal()?, minted.to_decimal()?)))
+    }
+
+    async fn get_statistics(&self) -> Result<Statistics> {
+        self.statistics(None).await
+    }
+
+    async fn get_statistics_since(&self, since: DateTime<Utc>) -> Result<Statistics> {
+        self.statistics(Some(&since.to_rfc3339())).await
+    }
+}
@@ -0,0 +1,247 @@
+use anyhow::Result;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::database::Database;
+use crate::minter::{read_keypair, TokenMinter, MIN_AUTHORITY_BALANCE_LAMPORTS};
+use crate::rpc::RpcPool;
+
+/// Outcome of one health check
+pub struct HealthCheck {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl HealthCheck {
+    fn pass(name: &'static str, detail: String) -> Self {
+        HealthCheck { name, passed: true, detail }
+    }
+
+    fn fail(name: &'static str, detail: String) -> Self {
+        HealthCheck { name, passed: false, detail }
+    }
+}
+
+/// Run every check without writing anything: the destination database is
+/// opened read-only and no transaction is sent
+pub async fn run_checks(config: &Config) -> Vec<HealthCheck> {
+    let rpc = RpcPool::new(
+        &config.x1_rpc_urls,
+        Duration::from_secs(config.rpc_timeout_secs),
+        CommitmentConfig::confirmed(),
+    );
+
+    let mut checks = vec![check_destination(config).await, check_sources(config)];
+    checks.extend(check_rpc(&rpc));
+    checks.push(check_keypair(config, &rpc));
+    checks.push(check_mint_decimals(config, &rpc));
+    if let Some(fee_payer_path) = &config.fee_payer_path {
+        checks.push(check_fee_payer(fee_payer_path, &rpc));
+    }
+    checks
+}
+
+/// `config check`: whether every setting points at something usable, without
+/// needing funded keys or an existing destination database
+pub async fn run_config_checks(config: &Config) -> Vec<HealthCheck> {
+    let rpc = RpcPool::new(
+        &config.x1_rpc_urls,
+        Duration::from_secs(config.rpc_timeout_secs),
+        CommitmentConfig::confirmed(),
+    );
+
+    let mut checks = vec![
+        check_sources(config),
+        check_destination_writable(config).await,
+        check_keypair_parses("Mint authority", &config.keypair_path),
+    ];
+    if let Some(fee_payer_path) = &config.fee_payer_path {
+        checks.push(check_keypair_parses("Fee payer", fee_payer_path));
+    }
+    checks.extend(check_rpc(&rpc));
+    checks.push(check_mint_account(config, &rpc));
+    checks
+}
+
+async fn check_destination(config: &Config) -> HealthCheck {
+    match Database::probe(config).await {
+        Ok(count) => HealthCheck::pass("Destination database", format!("{} records", count)),
+        Err(e) => HealthCheck::fail("Destination database", e.to_string()),
+    }
+}
+
+/// A SQLite destination must be writable, or creatable when it doesn't exist
+/// yet; a PostgreSQL one must accept a connection
+async fn check_destination_writable(config: &Config) -> HealthCheck {
+    const NAME: &str = "Destination database";
+    if Database::is_postgres_url(&config.database_url) {
+        return match Database::probe(config).await {
+            Ok(count) => HealthCheck::pass(NAME, format!("connected, {} records", count)),
+            Err(e) => HealthCheck::fail(NAME, e.to_string()),
+        };
+    }
+    let Some(path) = Database::sqlite_file(&config.database_url) else {
+        return HealthCheck::pass(NAME, "in memory".to_string());
+    };
+
+    if path.exists() {
+        // Opening for append neither creates nor changes the file
+        return match OpenOptions::new().append(true).open(&path) {
+            Ok(_) => HealthCheck::pass(NAME, format!("{} is writable", path.display())),
+            Err(e) => HealthCheck::fail(NAME, format!("{} is not writable: {}", path.display(), e)),
+        };
+    }
+    let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    match parent.metadata() {
+        Ok(metadata) if metadata.is_dir() && !metadata.permissions().readonly() => HealthCheck::pass(
+            NAME,
+            format!("{} will be created in {}", path.display(), parent.display()),
+        ),
+        Ok(_) => HealthCheck::fail(NAME, format!("{} is not a writable directory", parent.display())),
+        Err(e) => HealthCheck::fail(NAME, format!("{}: {}", parent.display(), e)),
+    }
+}
+
+fn check_sources(config: &Config) -> HealthCheck {
+    let (found, missing): (Vec<_>, Vec<_>) = config.source_db_paths.iter().partition(|path| path.exists());
+    let list = |paths: &[&std::path::PathBuf]| {
+        paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+    };
+
+    // Like migrate, a missing shard is tolerated as long as one source exists
+    match (found.is_empty(), missing.is_empty()) {
+        (true, _) => HealthCheck::fail("Source database", format!("not found: {}", list(&missing))),
+        (false, true) => HealthCheck::pass("Source database", list(&found)),
+        (false, false) => HealthCheck::pass(
+            "Source database",
+            format!("{} (missing: {})", list(&found), list(&missing)),
+        ),
+    }
+}
+
+/// One check per endpoint, so a dead fallback shows up before it's needed
+fn check_rpc(rpc: &RpcPool) -> Vec<HealthCheck> {
+    rpc.endpoints()
+        .iter()
+        .map(|rpc_client| match rpc_client.get_version() {
+            Ok(version) => HealthCheck::pass("X1 RPC", format!("{} (version {})", rpc_client.url(), version.solana_core)),
+            Err(e) => HealthCheck::fail("X1 RPC", format!("{}: {}", rpc_client.url(), e)),
+        })
+        .collect()
+}
+
+fn check_keypair(config: &Config, rpc: &RpcPool) -> HealthCheck {
+    let keypair = match TokenMinter::load_keypair(&config.keypair_path) {
+        Ok(Some(keypair)) => keypair,
+        Ok(None) => {
+            return HealthCheck::fail(
+                "Mint authority",
+                format!("no keypair at {}", config.keypair_path.display()),
+            )
+        }
+        Err(e) => return HealthCheck::fail("Mint authority", e.to_string()),
+    };
+
+    // With a separate fee payer the authority only signs and needs no SOL
+    if config.fee_payer_path.is_some() {
+        return HealthCheck::pass("Mint authority", keypair.pubkey().to_string());
+    }
+    check_balance("Mint authority", &keypair, rpc)
+}
+
+fn check_keypair_parses(name: &'static str, path: &Path) -> HealthCheck {
+    match read_keypair(path) {
+        Ok(keypair) => HealthCheck::pass(name, format!("{} ({})", keypair.pubkey(), path.display())),
+        Err(e) => HealthCheck::fail(name, format!("{}: {}", path.display(), e)),
+    }
+}
+
+/// The token mint must exist and be a Token-2022 mint, which is what every
+/// mint instruction is built for
+fn check_mint_account(config: &Config, rpc: &RpcPool) -> HealthCheck {
+    const NAME: &str = "Token mint";
+    let token_mint = match Pubkey::from_str(&config.token_mint) {
+        Ok(token_mint) => token_mint,
+        Err(e) => return HealthCheck::fail(NAME, format!("{}: {}", config.token_mint, e)),
+    };
+    let account = match rpc.call(|rpc| rpc.get_account(&token_mint)) {
+        Ok(account) => account,
+        Err(e) => return HealthCheck::fail(NAME, format!("{} unavailable: {}", token_mint, e)),
+    };
+    if account.owner != spl_token_2022::id() {
+        return HealthCheck::fail(NAME, format!("{} is owned by {}, not Token-2022", token_mint, account.owner));
+    }
+    match StateWithExtensions::<Mint>::unpack(&account.data) {
+        Ok(_) => HealthCheck::pass(NAME, format!("{} is a Token-2022 mint", token_mint)),
+        Err(e) => HealthCheck::fail(NAME, format!("{} is not a mint account: {}", token_mint, e)),
+    }
+}
+
+/// The mint's decimals must match the scaling every amount is stored with
+fn check_mint_decimals(config: &Config, rpc: &RpcPool) -> HealthCheck {
+    let token_mint = match Pubkey::from_str(&config.token_mint) {
+        Ok(token_mint) => token_mint,
+        Err(e) => return HealthCheck::fail("Mint decimals", format!("{}: {}", config.token_mint, e)),
+    };
+    let decimals = rpc.call(|rpc| rpc.get_account_data(&token_mint)).map_err(|e| e.to_string()).and_then(|data| {
+        StateWithExtensions::<Mint>::unpack(&data)
+            .map(|state| state.base.decimals)
+            .map_err(|e| e.to_string())
+    });
+    match decimals {
+        Ok(decimals) if decimals == config.token_decimals => {
+            HealthCheck::pass("Mint decimals", format!("{} has {} decimals", token_mint, decimals))
+        }
+        Ok(decimals) if config.skip_decimal_check => HealthCheck::pass(
+            "Mint decimals",
+            format!("{} has {} decimals, not {} (ignored)", token_mint, decimals, config.token_decimals),
+        ),
+        Ok(decimals) => HealthCheck::fail(
+            "Mint decimals",
+            format!("{} has {} decimals but amounts are scaled by {}", token_mint, decimals, config.token_decimals),
+        ),
+        Err(e) => HealthCheck::fail("Mint decimals", format!("{} unavailable: {}", token_mint, e)),
+    }
+}
+
+fn check_fee_payer(path: &Path, rpc: &RpcPool) -> HealthCheck {
+    match read_keypair(path) {
+        Ok(keypair) => check_balance("Fee payer", &keypair, rpc),
+        Err(e) => HealthCheck::fail("Fee payer", e.to_string()),
+    }
+}
+
+fn check_balance(name: &'static str, keypair: &Keypair, rpc: &RpcPool) -> HealthCheck {
+    match rpc.call(|rpc| rpc.get_balance(&keypair.pubkey())) {
+        Ok(balance) if balance >= MIN_AUTHORITY_BALANCE_LAMPORTS => HealthCheck::pass(
+            name,
+            format!("{} holds {:.4} SOL", keypair.pubkey(), balance as f64 / 1_000_000_000.0),
+        ),
+        Ok(balance) => HealthCheck::fail(
+            name,
+            format!("{} holds only {:.4} SOL", keypair.pubkey(), balance as f64 / 1_000_000_000.0),
+        ),
+        Err(e) => HealthCheck::fail(name, format!("balance of {} unavailable: {}", keypair.pubkey(), e)),
+    }
+}
+
+/// Print the checks and fail when any of them did
+pub fn report(checks: &[HealthCheck]) -> Result<()> {
+    for check in checks {
+        let mark = if check.passed { "✅" } else { "❌" };
+        println!("{} {:<22} {}", mark, check.name, check.detail);
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed).count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} checks failed", failed, checks.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use log::info;
+
+use crate::config::Config;
+use crate::types::BurnRecord;
+use crate::webhook::WebhookNotifier;
+
+/// A side effect run after each mint, such as a notification. Hooks are
+/// best effort: they log their own failures and never fail the mint, which
+/// is already recorded by the time they run.
+#[async_trait]
+pub trait PostMintHook: Send + Sync {
+    /// `record` was minted in `signature`, `minted_amount` raw units
+    async fn on_mint_success(&self, record: &BurnRecord, signature: &str, minted_amount: u64);
+    
+    /// Minting `record` failed with `error`; it stays pending
+    async fn on_mint_failure(&self, _record: &BurnRecord, _error: &str) {}
+}
+
+/// The hooks this deployment configured, in the order they run
+pub fn from_config(config: &Config) -> Result<Vec<Box<dyn PostMintHook>>> {
+    let mut hooks: Vec<Box<dyn PostMintHook>> = Vec::new();
+    if let Some(url) = &config.webhook_url {
+        info!("Mint notifications will be sent to webhook");
+        hooks.push(Box::new(WebhookNotifier::new(url, &config.x1_explorer_base)?));
+    }
+    Ok(hooks)
+}
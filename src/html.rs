@@ -1,32 +1,50 @@
 use anyhow::Result;
-use chrono::Utc;
-use log::info;
-use tera::{Context, Tera};
+use chrono::{NaiveDate, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::{info, warn};
+use tera::{Context, Tera, Value};
 use serde::{Serialize, Deserialize};
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
 
+use crate::config::Config;
 use crate::database::Database;
+use crate::types::{BurnRecord, MemoCheck, RecordFilter, Statistics, WalletSummary};
+
+/// Records rendered into the initial HTML; the rest are paged in client-side
+const RECORDS_PAGE_SIZE: usize = 100;
+
+/// Directory under output_dir holding the per-wallet pages
+const WALLET_PAGES_DIR: &str = "wallet";
 
 //  Template for the HTML report
 #[derive(Serialize, Deserialize)]
 struct TemplateWalletSummary {
     pub wallet_address: String,
-    pub total_burned: f64,
-    pub total_minted: f64,
+    pub total_burned: Decimal,
+    pub total_minted: Decimal,
     pub burn_count: i64,
     pub mint_count: i64,
     pub first_burn: Option<String>,
     pub last_mint: Option<String>,
+    pub page: Option<String>, // Link to the wallet's own page, with per_wallet_pages
 }
 
 #[derive(Serialize, Deserialize)]
 struct TemplateStatistics {
     pub total_records: i64,
-    pub total_burned_amount: f64,
-    pub total_minted_amount: f64,
+    pub total_burned_amount: Decimal,
+    pub total_minted_amount: Decimal,
     pub unique_wallets: i64,
     pub pending_mints: i64,
     pub successful_mints: i64,
+    pub failed_mints: i64,
+    pub total_fees: Decimal, // SOL
+    pub success_rate: Option<f64>, // Percentage; shown as N/A when unset
 }
 
 #[derive(Serialize, Deserialize)]
@@ -34,113 +52,454 @@ struct TemplateBurnRecord {
     pub id: Option<i64>,
     pub signature: String,
     pub burner: String,
-    pub amount_decimal: f64, // Convert to f64 for display
+    pub amount_raw: u64, // Base units, the sort key
+    pub amount_decimal: Decimal, // Serialized as a string, so it's exact
+    pub amount_display: String, // Formatted from amount_decimal, e.g. "1,234.56"
     pub memo: Option<String>,
     pub token: Option<String>,
     pub timestamp: Option<String>,
-    pub memo_checked: Option<String>,
+    pub timestamp_sort: Option<String>, // RFC3339 for client-side date sorting
+    pub timestamp_is_fallback: bool, // burn time unknown, `timestamp` shows created_at
+    pub memo_checked: MemoCheck,
     pub created_at: String,
     pub is_minted: bool,
     pub minted_time: Option<String>,
     pub minted_signature: Option<String>,
 }
 
+// One records-table row in the JSON island the client-side pager renders from;
+// the short keys keep the page small with tens of thousands of records
+#[derive(Serialize)]
+struct RecordsTableRow<'a> {
+    t: &'a str,                  // displayed time
+    ts: &'a str,                 // RFC3339 sort key
+    f: bool,                     // time is the created_at fallback
+    w: &'a str,                  // burner wallet
+    a: u64,                      // amount in base units, for sorting
+    ad: &'a str,                 // amount as displayed
+    s: &'a str,                  // burn signature
+    m: bool,                     // minted
+    x: Option<&'a str>,          // mint signature
+    v: MemoCheck,                // memo check
+}
+
+// One row of the Top Burners leaderboard
+#[derive(Serialize, Deserialize)]
+struct TemplateTopBurner {
+    pub rank: usize,
+    pub wallet_address: String,
+    pub total_burned: Decimal,
+    pub burn_count: i64,
+}
+
+// One row of the Recent Activity list
+#[derive(Serialize, Deserialize)]
+struct TemplateRecentMint {
+    pub burner: String,
+    pub signature: String,
+    pub minted_amount: Decimal,
+    pub minted_time: String,
+    pub minted_time_sort: String, // RFC3339 for client-side date sorting
+    pub minted_signature: String,
+}
+
+// Burned and minted totals of one day; burns count on their burn day, mints on their mint day
+#[derive(Serialize, Deserialize)]
+struct TemplateDailyTotal {
+    pub date: String,
+    pub burned: Decimal,
+    pub burn_count: usize,
+    pub minted: Decimal,
+    pub mint_count: usize,
+}
+
+// Daily totals for the activity chart, one entry per day in each series
+#[derive(Serialize, Deserialize)]
+struct ChartData {
+    pub labels: Vec<String>,
+    pub burned: Vec<Decimal>, // Serialized as strings, which Chart.js parses
+    pub minted: Vec<Decimal>,
+}
+
+// Machine-readable companion to the HTML report; amounts serialize as decimal strings
+#[derive(Serialize)]
+struct JsonSummary<'a> {
+    pub generated_at: String,
+    pub since: Option<String>, // Set when the report only covers recent records
+    pub record_count: usize,
+    pub stats: &'a Statistics,
+    pub wallet_summaries: &'a [WalletSummary],
+}
+
 pub struct HtmlGenerator<'a> {
+    config: &'a Config,
     db: &'a Database,
 }
 
 impl<'a> HtmlGenerator<'a> {
-    pub fn new(db: &'a Database) -> Self {
-        Self { db }
+    pub fn new(config: &'a Config, db: &'a Database) -> Self {
+        Self { config, db }
     }
     
     pub async fn generate(&self) -> Result<()> {
-        let records = self.db.get_all_records().await?;
-        let wallet_summaries = self.db.get_wallet_summaries().await?;
-        let stats = self.db.get_statistics().await?;
+        // With report_since only recent activity is reported, stats included
+        let (mut records, mut wallet_summaries, stats) = match self.config.report_since {
+            Some(since) => {
+                info!("Reporting records created or minted since {}", since.to_rfc3339());
+                (
+                    self.report_records().await?,
+                    self.db.get_wallet_summaries_since(since).await?,
+                    self.db.get_statistics_since(since).await?,
+                )
+            }
+            None => (
+                self.report_records().await?,
+                self.db.get_wallet_summaries().await?,
+                self.db.get_statistics().await?,
+            ),
+        };
+        // Mask before anything is derived from the data, so no artifact sees a real address
+        if self.config.anonymize {
+            info!("Anonymizing wallet addresses and signatures");
+            records.iter_mut().for_each(anonymize_record);
+            for wallet in &mut wallet_summaries {
+                wallet.wallet_address = anonymize(&wallet.wallet_address);
+            }
+        }
+        let record_filter = describe_filter(&self.config.report_filter, &self.config.token_symbol);
+        if let Some(description) = &record_filter {
+            info!("Listing {} records with {}", records.len(), description);
+        }
+        let daily_totals = Self::daily_totals(&records);
+        let chart_data = Self::daily_chart_data(&daily_totals);
+        let top_burners = Self::top_burners(&wallet_summaries, self.config.top_burners);
+        let recent_mints = self.recent_mints().await?;
+        
+        if !self.config.output_dir.as_os_str().is_empty() {
+            std::fs::create_dir_all(&self.config.output_dir).map_err(|e| {
+                anyhow::anyhow!("Failed to create output directory {}: {}", self.config.output_dir.display(), e)
+            })?;
+        }
+        
+        let summary = JsonSummary {
+            generated_at: Utc::now().to_rfc3339(),
+            since: self.config.report_since.map(|since| since.to_rfc3339()),
+            record_count: records.len(),
+            stats: &stats,
+            wallet_summaries: &wallet_summaries,
+        };
+        let json_path = self.config.output_dir.join(&self.config.json_file_name);
+        self.write_output(&json_path, serde_json::to_string_pretty(&summary)?.as_bytes())?;
+        info!("JSON summary generated: {}", json_path.display());
         
         // Convert data to template-friendly format
-        let template_records: Vec<TemplateBurnRecord> = records.into_iter().map(|record| {
-            // Calculate values first to avoid partial moves
-            let amount_decimal = record.amount_as_decimal().to_f64().unwrap_or(0.0);
-            let timestamp_str = record.timestamp.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
-            let created_at_str = record.created_at.format("%Y-%m-%d %H:%M").to_string();
-            let minted_time_str = record.minted_time.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
-            
-            TemplateBurnRecord {
-                id: record.id,
-                signature: record.signature,
-                burner: record.burner,
-                amount_decimal,
-                memo: record.memo,
-                token: record.token,
-                timestamp: timestamp_str,
-                memo_checked: record.memo_checked,
-                created_at: created_at_str,
-                is_minted: record.is_minted,
-                minted_time: minted_time_str,
-                minted_signature: record.minted_signature,
-            }
-        }).collect();
+        let template_records: Vec<TemplateBurnRecord> = records.into_iter().map(template_record).collect();
         
         let template_wallet_summaries: Vec<TemplateWalletSummary> = wallet_summaries.into_iter().map(|wallet| {
             // Calculate values first to avoid partial moves
-            let total_burned_f64 = wallet.total_burned.to_f64().unwrap_or(0.0);
-            let total_minted_f64 = wallet.total_minted.to_f64().unwrap_or(0.0);
             let first_burn_str = wallet.first_burn.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
             let last_mint_str = wallet.last_mint.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
+            let page = wallet_page_name(&wallet.wallet_address)
+                .filter(|_| self.wallet_pages())
+                .map(|name| format!("{}/{}", WALLET_PAGES_DIR, name));
             
             TemplateWalletSummary {
                 wallet_address: wallet.wallet_address,
-                total_burned: total_burned_f64,
-                total_minted: total_minted_f64,
+                total_burned: wallet.total_burned,
+                total_minted: wallet.total_minted,
                 burn_count: wallet.burn_count,
                 mint_count: wallet.mint_count,
                 first_burn: first_burn_str,
                 last_mint: last_mint_str,
+                page,
             }
         }).collect();
         
         let template_stats = TemplateStatistics {
             total_records: stats.total_records,
-            total_burned_amount: stats.total_burned_amount.to_f64().unwrap_or(0.0),
-            total_minted_amount: stats.total_minted_amount.to_f64().unwrap_or(0.0),
+            total_burned_amount: stats.total_burned_amount,
+            total_minted_amount: stats.total_minted_amount,
             unique_wallets: stats.unique_wallets,
             pending_mints: stats.pending_mints,
             successful_mints: stats.successful_mints,
+            failed_mints: stats.failed_mints,
+            total_fees: Decimal::from_i128_with_scale(i128::from(stats.total_fees_lamports), 9),
+            success_rate: stats.success_rate.map(|rate| rate * 100.0),
         };
         
-        let template = self.get_template();
+        let template = self.get_template()?;
         
         let mut context = Context::new();
-        context.insert("records", &template_records);
+        // Only the first page is rendered as HTML; every record is embedded as
+        // JSON so paging, search and sorting cover the whole set without the
+        // page carrying tens of thousands of table rows
+        let records_json = records_json(&template_records)?;
+        context.insert("records", &template_records[..template_records.len().min(RECORDS_PAGE_SIZE)]);
+        context.insert("record_count", &template_records.len());
+        context.insert("records_page_size", &RECORDS_PAGE_SIZE);
+        context.insert("records_json", &records_json);
         context.insert("wallet_summaries", &template_wallet_summaries);
         context.insert("stats", &template_stats);
+        context.insert("chart_data", &chart_data);
+        context.insert("top_burners", &top_burners);
+        context.insert("recent_mints", &recent_mints);
+        // Newest day first in the table; the chart reads oldest first
+        context.insert("daily_totals", &daily_totals.iter().rev().collect::<Vec<_>>());
+        context.insert("theme", &self.config.theme);
+        self.insert_labels(&mut context);
+        context.insert("solana_explorer_base", &self.config.solana_explorer_base);
+        context.insert("x1_explorer_base", &self.config.x1_explorer_base);
+        context.insert(
+            "report_since",
+            &self.config.report_since.map(|since| since.format("%Y-%m-%d %H:%M UTC").to_string()),
+        );
+        context.insert("record_filter", &record_filter);
+        context.insert("anonymize", &self.config.anonymize);
         context.insert("last_updated", &Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string());
         
         let html = template.render("index", &context)?;
         
-        std::fs::write("index.html", html)?;
-        info!("HTML report generated: index.html");
+        if self.wallet_pages() {
+            self.generate_wallet_pages(&template, &template_wallet_summaries).await?;
+        } else if self.config.per_wallet_pages {
+            warn!("Skipping wallet pages: their file names would give away the addresses");
+        }
+        
+        let html_path = self.config.output_dir.join(&self.config.html_file_name);
+        self.write_output(&html_path, html.as_bytes())?;
+        info!("HTML report generated: {}", html_path.display());
         
         Ok(())
     }
     
-    fn get_template(&self) -> Tera {
-        let mut tera = Tera::new("templates/*").unwrap_or_else(|_| Tera::new("").unwrap());
-        tera.add_raw_template("index", &self.get_template_content()).unwrap();
-        tera
+    /// Write `wallet/<address>.html` for every wallet with a page link,
+    /// scoped to report_since like the main report
+    async fn generate_wallet_pages(&self, template: &Tera, wallets: &[TemplateWalletSummary]) -> Result<()> {
+        let dir = self.config.output_dir.join(WALLET_PAGES_DIR);
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            anyhow::anyhow!("Failed to create wallet page directory {}: {}", dir.display(), e)
+        })?;
+        
+        let mut context = Context::new();
+        context.insert("theme", &self.config.theme);
+        self.insert_labels(&mut context);
+        context.insert("solana_explorer_base", &self.config.solana_explorer_base);
+        context.insert("x1_explorer_base", &self.config.x1_explorer_base);
+        context.insert("report_file_name", &self.config.html_file_name);
+        context.insert(
+            "report_since",
+            &self.config.report_since.map(|since| since.format("%Y-%m-%d %H:%M UTC").to_string()),
+        );
+        context.insert("last_updated", &Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string());
+        
+        let mut written = 0;
+        for wallet in wallets {
+            let Some(page) = &wallet.page else {
+                warn!("No wallet page for {:?}: not a safe file name", wallet.wallet_address);
+                continue;
+            };
+            let records: Vec<TemplateBurnRecord> = self.db.get_records_for_burner(&wallet.wallet_address).await?
+                .into_iter()
+                .filter(|record| match self.config.report_since {
+                    Some(since) => record.created_at > since || record.minted_time.is_some_and(|t| t > since),
+                    None => true,
+                })
+                .map(template_record)
+                .collect();
+            
+            context.insert("wallet", wallet);
+            context.insert("records", &records);
+            let html = template.render("wallet", &context)?;
+            self.write_output(&self.config.output_dir.join(page), html.as_bytes())?;
+            written += 1;
+        }
+        info!("Wallet pages generated: {} in {}", written, dir.display());
+        
+        Ok(())
     }
     
-    fn get_template_content(&self) -> String {
-        r#"<!DOCTYPE html>
-<html lang="en">
-<head>
-    <meta charset="UTF-8">
-    <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>solXEN  - X1 Testnet</title>
-    <link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.0.0/css/all.min.css">
+    /// Whether wallet pages are written; never when anonymizing, since each is
+    /// named after its address
+    fn wallet_pages(&self) -> bool {
+        self.config.per_wallet_pages && !self.config.anonymize
+    }
+    
+    /// Records listed in the report: those matching the record filter,
+    /// scoped to report_since
+    async fn report_records(&self) -> Result<Vec<BurnRecord>> {
+        let filter = RecordFilter { since: self.config.report_since, ..self.config.report_filter.clone() };
+        self.db.get_records_filtered(&filter, None, 0).await
+    }
+    
+    /// Names the templates show instead of hardcoding this deployment's
+    fn insert_labels(&self, context: &mut Context) {
+        context.insert("report_title", &self.config.report_title);
+        context.insert("token_symbol", &self.config.token_symbol);
+        context.insert("source_chain_name", &self.config.source_chain_name);
+        context.insert("dest_chain_name", &self.config.dest_chain_name);
+        context.insert("network_label", self.config.network.preset().label);
+    }
+    
+    /// Write a report artifact and, with gzip_output, a pre-compressed
+    /// `<name>.gz` copy next to it
+    fn write_output(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        write_atomic(path, contents)?;
+        if self.config.gzip_output {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(contents)?;
+            let mut gz_name = path.as_os_str().to_owned();
+            gz_name.push(".gz");
+            write_atomic(Path::new(&gz_name), &encoder.finish()?)?;
+        }
+        Ok(())
+    }
+    
+    /// The `limit` wallets that burned the most, largest first
+    fn top_burners(wallet_summaries: &[WalletSummary], limit: usize) -> Vec<TemplateTopBurner> {
+        let mut wallets: Vec<&WalletSummary> = wallet_summaries.iter().collect();
+        wallets.sort_by_key(|wallet| std::cmp::Reverse(wallet.total_burned));
+        
+        wallets.into_iter().take(limit).enumerate().map(|(index, wallet)| TemplateTopBurner {
+            rank: index + 1,
+            wallet_address: wallet.wallet_address.clone(),
+            total_burned: wallet.total_burned,
+            burn_count: wallet.burn_count,
+        }).collect()
+    }
+    
+    /// The latest `recent_mints` mints across the whole database, newest first
+    async fn recent_mints(&self) -> Result<Vec<TemplateRecentMint>> {
+        if self.config.recent_mints == 0 {
+            return Ok(Vec::new());
+        }
+        let mut records = self.db.get_recent_mints(self.config.recent_mints as i64).await?;
+        if self.config.anonymize {
+            records.iter_mut().for_each(anonymize_record);
+        }
+        Ok(records.into_iter().filter_map(|record| {
+            let minted_amount = record.minted_amount_as_decimal().unwrap_or_else(|| record.amount_as_decimal());
+            let minted_time = record.minted_time?;
+            Some(TemplateRecentMint {
+                minted_amount,
+                minted_time: minted_time.format("%Y-%m-%d %H:%M").to_string(),
+                minted_time_sort: minted_time.to_rfc3339(),
+                minted_signature: record.minted_signature?,
+                burner: record.burner,
+                signature: record.signature,
+            })
+        }).collect())
+    }
+    
+    /// Group burns by burn day and mints by mint day, oldest day first; records
+    /// without the relevant timestamp are left out (they still appear in the tables)
+    fn daily_totals(records: &[BurnRecord]) -> Vec<TemplateDailyTotal> {
+        #[derive(Default)]
+        struct Day {
+            burned: Decimal,
+            burn_count: usize,
+            minted: Decimal,
+            mint_count: usize,
+        }
+        let mut days: BTreeMap<NaiveDate, Day> = BTreeMap::new();
+        
+        for record in records {
+            if let Some(timestamp) = record.timestamp {
+                let day = days.entry(timestamp.date_naive()).or_default();
+                day.burned += record.amount_as_decimal();
+                day.burn_count += 1;
+            }
+            if let (true, Some(minted_time), Some(minted)) =
+                (record.is_minted, record.minted_time, record.minted_amount_as_decimal())
+            {
+                let day = days.entry(minted_time.date_naive()).or_default();
+                day.minted += minted;
+                day.mint_count += 1;
+            }
+        }
+        
+        days.into_iter().map(|(date, day)| TemplateDailyTotal {
+            date: date.format("%Y-%m-%d").to_string(),
+            burned: day.burned,
+            burn_count: day.burn_count,
+            minted: day.minted,
+            mint_count: day.mint_count,
+        }).collect()
+    }
+    
+    fn daily_chart_data(daily_totals: &[TemplateDailyTotal]) -> ChartData {
+        ChartData {
+            labels: daily_totals.iter().map(|day| day.date.clone()).collect(),
+            burned: daily_totals.iter().map(|day| day.burned).collect(),
+            minted: daily_totals.iter().map(|day| day.minted).collect(),
+        }
+    }
+    
+    fn get_template(&self) -> Result<Tera> {
+        let mut tera = Tera::new("templates/*").unwrap_or_default();
+        tera.register_filter("format_amount", format_amount);
+        tera.add_raw_templates(vec![
+            ("head", self.get_head_content()),
+            ("index", self.get_template_content()),
+            ("wallet", self.get_wallet_template_content()),
+        ])?;
+        Ok(tera)
+    }
+    
+    /// Stylesheet and theme script shared by the report and the wallet pages
+    fn get_head_content(&self) -> String {
+        r#"<link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.0.0/css/all.min.css">
+    <script>
+        // Apply the stored or configured theme before first paint
+        (function () {
+            const stored = localStorage.getItem('solxen-theme');
+            let theme = stored || document.documentElement.dataset.themeDefault;
+            if (theme !== 'light' && theme !== 'dark') {
+                theme = window.matchMedia('(prefers-color-scheme: dark)').matches ? 'dark' : 'light';
+            }
+            document.documentElement.dataset.theme = theme;
+        })();
+
+        function updateThemeIcon() {
+            const dark = document.documentElement.dataset.theme === 'dark';
+            document.querySelector('#themeToggle i').className = dark ? 'fas fa-sun' : 'fas fa-moon';
+        }
+
+        function toggleTheme() {
+            const next = document.documentElement.dataset.theme === 'dark' ? 'light' : 'dark';
+            document.documentElement.dataset.theme = next;
+            localStorage.setItem('solxen-theme', next);
+            updateThemeIcon();
+        }
+
+        document.addEventListener('DOMContentLoaded', updateThemeIcon);
+    </script>
     <style>
+        :root {
+            --page-bg: #f5f5f5;
+            --card-bg: white;
+            --text: #333;
+            --heading: #2c3e50;
+            --muted: #7f8c8d;
+            --subtle-bg: #f8f9fa;
+            --border: #dee2e6;
+            --divider: #eee;
+            --input-border: #ddd;
+            --table-heading: #495057;
+        }
+
+        [data-theme="dark"] {
+            --page-bg: #121417;
+            --card-bg: #1e2227;
+            --text: #d8dde3;
+            --heading: #e8ecf1;
+            --muted: #9aa5b1;
+            --subtle-bg: #262b31;
+            --border: #353b42;
+            --divider: #2c3238;
+            --input-border: #3d444c;
+            --table-heading: #b8c1cb;
+        }
+
         * {
             margin: 0;
             padding: 0;
@@ -149,8 +508,8 @@ impl<'a> HtmlGenerator<'a> {
 
         body {
             font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
-            background-color: #f5f5f5;
-            color: #333;
+            background-color: var(--page-bg);
+            color: var(--text);
             line-height: 1.6;
             display: flex;
             flex-direction: column;
@@ -174,13 +533,32 @@ impl<'a> HtmlGenerator<'a> {
 
         .header h1 {
             font-size: 3rem;
-            color: #2c3e50;
+            color: var(--heading);
             margin-bottom: 10px;
         }
 
         .header p {
             font-size: 1.2rem;
-            color: #7f8c8d;
+            color: var(--muted);
+        }
+
+        .header p.report-scope {
+            font-size: 1rem;
+            margin-top: 8px;
+        }
+
+        .theme-toggle {
+            position: absolute;
+            top: 0;
+            right: 0;
+            background: var(--card-bg);
+            color: var(--heading);
+            border: 1px solid var(--border);
+            border-radius: 50%;
+            width: 40px;
+            height: 40px;
+            cursor: pointer;
+            font-size: 1rem;
         }
 
         /* Stats Section */
@@ -200,7 +578,7 @@ impl<'a> HtmlGenerator<'a> {
             grid-template-columns: repeat(4, 1fr);
             gap: 12px;
             padding: 12px;
-            background-color: white;
+            background-color: var(--card-bg);
             border-radius: 10px;
             box-shadow: 0 2px 8px rgba(0, 0, 0, 0.08);
         }
@@ -215,7 +593,7 @@ impl<'a> HtmlGenerator<'a> {
             transition: all 0.3s ease;
             border: 1px solid transparent;
             min-height: 80px;
-            background-color: #f8f9fa;
+            background-color: var(--subtle-bg);
         }
 
         .stat-item.burn {
@@ -242,6 +620,12 @@ impl<'a> HtmlGenerator<'a> {
             border-color: #d6d8db;
         }
 
+        .stat-item.failed {
+            background-color: #f8d7da;
+            color: #721c24;
+            border-color: #f5c6cb;
+        }
+
         .stat-icon {
             font-size: 24px;
             margin-bottom: 8px;
@@ -262,7 +646,7 @@ impl<'a> HtmlGenerator<'a> {
 
         /* Results sections */
         .results {
-            background-color: white;
+            background-color: var(--card-bg);
             border-radius: 10px;
             box-shadow: 0 2px 10px rgba(0, 0, 0, 0.1);
             padding: 30px;
@@ -276,11 +660,11 @@ impl<'a> HtmlGenerator<'a> {
             align-items: center;
             margin-bottom: 20px;
             padding-bottom: 15px;
-            border-bottom: 2px solid #eee;
+            border-bottom: 2px solid var(--divider);
         }
 
         .result-header h2 {
-            color: #2c3e50;
+            color: var(--heading);
             font-size: 1.5rem;
             display: flex;
             align-items: center;
@@ -296,6 +680,36 @@ impl<'a> HtmlGenerator<'a> {
             font-weight: 500;
         }
 
+        /* Records pager */
+        .pager {
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            gap: 15px;
+            margin-top: 20px;
+            color: var(--muted);
+        }
+
+        .pager button {
+            padding: 8px 16px;
+            border: 2px solid var(--input-border);
+            border-radius: 20px;
+            background-color: var(--card-bg);
+            color: var(--text);
+            cursor: pointer;
+        }
+
+        .pager button:disabled {
+            opacity: 0.4;
+            cursor: default;
+        }
+
+        /* Chart */
+        .chart-container {
+            position: relative;
+            height: 320px;
+        }
+
         /* Search Container */
         .search-container {
             margin-bottom: 20px;
@@ -309,9 +723,11 @@ impl<'a> HtmlGenerator<'a> {
         .search-box input {
             width: 100%;
             padding: 12px 40px 12px 16px;
-            border: 2px solid #ddd;
+            border: 2px solid var(--input-border);
             border-radius: 25px;
             font-size: 14px;
+            background-color: var(--card-bg);
+            color: var(--text);
             transition: border-color 0.3s;
         }
 
@@ -338,29 +754,50 @@ impl<'a> HtmlGenerator<'a> {
         table {
             width: 100%;
             border-collapse: collapse;
-            background-color: white;
+            background-color: var(--card-bg);
         }
 
         thead {
-            background-color: #f8f9fa;
+            background-color: var(--subtle-bg);
         }
 
         th, td {
             padding: 15px;
             text-align: left;
-            border-bottom: 1px solid #dee2e6;
+            border-bottom: 1px solid var(--border);
         }
 
         th {
             font-weight: 600;
-            color: #495057;
+            color: var(--table-heading);
             text-transform: uppercase;
             font-size: 0.85rem;
             letter-spacing: 0.5px;
         }
 
         tbody tr:hover {
-            background-color: #f8f9fa;
+            background-color: var(--subtle-bg);
+        }
+
+        th[data-sort-type] {
+            cursor: pointer;
+            user-select: none;
+            white-space: nowrap;
+        }
+
+        th[data-sort-type]::after {
+            content: ' \2195';
+            color: #bbb;
+        }
+
+        th.sort-asc::after {
+            content: ' \2191';
+            color: #3498db;
+        }
+
+        th.sort-desc::after {
+            content: ' \2193';
+            color: #3498db;
         }
 
         .address-link {
@@ -380,6 +817,11 @@ impl<'a> HtmlGenerator<'a> {
             text-align: right;
         }
 
+        .fallback-time {
+            font-style: italic;
+            color: #7f8c8d;
+        }
+
         .status-badge {
             padding: 6px 12px;
             border-radius: 12px;
@@ -404,6 +846,18 @@ impl<'a> HtmlGenerator<'a> {
             color: #721c24;
         }
 
+        .memo-check {
+            margin-left: 4px;
+        }
+
+        .memo-check.verified {
+            color: #27ae60;
+        }
+
+        .memo-check.rejected {
+            color: #e74c3c;
+        }
+
         /* Footer */
         .footer {
             background-color: #2c3e50;
@@ -446,33 +900,235 @@ impl<'a> HtmlGenerator<'a> {
                 font-size: 0.9rem;
             }
         }
-    </style>
+    </style>"#.to_string()
+    }
+    
+    /// Page of one wallet: its totals and every one of its transactions
+    fn get_wallet_template_content(&self) -> String {
+        r#"<!DOCTYPE html>
+<html lang="en" data-theme-default="{{ theme }}">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{{ wallet.wallet_address }} - {{ token_symbol }} {{ dest_chain_name }}{% if network_label %} {{ network_label }}{% endif %}</title>
+    {% include "head" %}
+</head>
+<body>
+    <main class="container">
+        <div class="header">
+            <button id="themeToggle" class="theme-toggle" onclick="toggleTheme()" title="Toggle dark mode">
+                <i class="fas fa-moon"></i>
+            </button>
+            <h1><i class="fas fa-wallet"></i> Wallet</h1>
+            <p>
+                <a href="{{ solana_explorer_base }}/account/{{ wallet.wallet_address }}" 
+                   target="_blank" class="address-link">{{ wallet.wallet_address }}</a>
+            </p>
+            <p class="report-scope"><a href="../{{ report_file_name }}" class="address-link">&laquo; Back to the report</a></p>
+            {% if report_since %}
+            <p class="report-scope">Showing records created or minted since {{ report_since }}</p>
+            {% endif %}
+        </div>
+
+        <div class="stats-section">
+            <div class="stats-container">
+                <div class="stats-grid">
+                    <div class="stat-item burn">
+                        <div class="stat-icon"><i class="fas fa-fire"></i></div>
+                        <div class="stat-label">Total Burned ({{ source_chain_name }})</div>
+                        <div class="stat-value">{{ wallet.total_burned | format_amount }}</div>
+                    </div>
+                    <div class="stat-item mint">
+                        <div class="stat-icon"><i class="fas fa-coins"></i></div>
+                        <div class="stat-label">Total Minted ({{ dest_chain_name }})</div>
+                        <div class="stat-value">{{ wallet.total_minted | format_amount }}</div>
+                    </div>
+                    <div class="stat-item pending">
+                        <div class="stat-icon"><i class="fas fa-list"></i></div>
+                        <div class="stat-label">Transactions</div>
+                        <div class="stat-value">{{ wallet.burn_count }}</div>
+                    </div>
+                </div>
+            </div>
+        </div>
+
+        <div class="results">
+            <div class="result-header">
+                <h2><i class="fas fa-list"></i> Transaction Records</h2>
+                <span class="result-type">{{ records | length }} transactions</span>
+            </div>
+            <div class="table-container">
+                <table>
+                    <thead>
+                        <tr>
+                            <th>Time</th>
+                            <th>Amount</th>
+                            <th>Solana Tx</th>
+                            <th>Status</th>
+                            <th>X1 Tx</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {% for record in records %}
+                        <tr>
+                            {% if record.timestamp_is_fallback %}
+                            <td class="fallback-time"
+                                title="Burn time unknown; showing when the record was created">{{ record.timestamp }} (created)</td>
+                            {% else %}
+                            <td>{{ record.timestamp }}</td>
+                            {% endif %}
+                            <td class="amount">{{ record.amount_display }}</td>
+                            <td>
+                                <a href="{{ solana_explorer_base }}/tx/{{ record.signature }}" 
+                                   target="_blank" class="address-link">
+                                    {{ record.signature | truncate(length=12) }}
+                                </a>
+                                {% if record.memo_checked == "verified" %}
+                                <i class="fas fa-circle-check memo-check verified" title="Memo verified"></i>
+                                {% elif record.memo_checked == "rejected" %}
+                                <i class="fas fa-circle-xmark memo-check rejected" title="Memo rejected"></i>
+                                {% endif %}
+                            </td>
+                            <td>
+                                {% if record.is_minted %}
+                                <span class="status-badge success">Minted</span>
+                                {% else %}
+                                <span class="status-badge pending">Pending</span>
+                                {% endif %}
+                            </td>
+                            <td>
+                                {% if record.minted_signature %}
+                                <a href="{{ x1_explorer_base }}/tx/{{ record.minted_signature }}" 
+                                   target="_blank" class="address-link">
+                                    {{ record.minted_signature | truncate(length=12) }}
+                                </a>
+                                {% else %}
+                                <span class="status-badge pending">Waiting</span>
+                                {% endif %}
+                            </td>
+                        </tr>
+                        {% endfor %}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+    </main>
+
+    <footer class="footer">
+        <div class="footer-content">
+            <p>{{ report_title }} - Last updated: {{ last_updated }}</p>
+            <p>Powered by Rust and {{ dest_chain_name }}{% if network_label %} {{ network_label }}{% endif %}</p>
+        </div>
+    </footer>
+</body>
+</html>"#.to_string()
+    }
+    
+    fn get_template_content(&self) -> String {
+        r#"<!DOCTYPE html>
+<html lang="en" data-theme-default="{{ theme }}">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{{ token_symbol }}  - {{ dest_chain_name }}{% if network_label %} {{ network_label }}{% endif %}</title>
+    {% include "head" %}
+    <script src="https://cdn.jsdelivr.net/npm/chart.js@4.4.0/dist/chart.umd.min.js"></script>
 </head>
 <body>
     <main class="container">
         <!-- Header -->
         <div class="header">
-            <h1><i class="fas fa-exchange-alt"></i> solXEN is The Second Best</h1>
-            <p>solXEN rises anew on X1 Blockchain.</p>
+            <button id="themeToggle" class="theme-toggle" onclick="toggleTheme()" title="Toggle dark mode">
+                <i class="fas fa-moon"></i>
+            </button>
+            <h1><i class="fas fa-exchange-alt"></i> {{ report_title }}</h1>
+            <p>{{ token_symbol }} rises anew on {{ dest_chain_name }} Blockchain.</p>
+            {% if report_since %}
+            <p class="report-scope">Showing records created or minted since {{ report_since }}</p>
+            {% endif %}
+            {% if record_filter %}
+            <p class="report-scope">Listing only records with {{ record_filter }}; statistics and wallet totals are not filtered</p>
+            {% endif %}
         </div>
 
+        {% if recent_mints %}
+        <!-- Recent Activity -->
+        <div class="results">
+            <div class="result-header">
+                <h2><i class="fas fa-bolt"></i> Recent Activity</h2>
+                <span class="result-type">Latest {{ recent_mints | length }}</span>
+            </div>
+            <div class="table-container">
+                <table>
+                    <thead>
+                        <tr>
+                            <th data-sort-type="date">Minted</th>
+                            <th data-sort-type="string">Wallet Address</th>
+                            <th data-sort-type="number">Amount ({{ dest_chain_name }})</th>
+                            <th data-sort-type="string">Burn</th>
+                            <th data-sort-type="string">Mint</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {% for mint in recent_mints %}
+                        <tr>
+                            <td data-sort-value="{{ mint.minted_time_sort }}">{{ mint.minted_time }}</td>
+                            <td data-sort-value="{{ mint.burner }}">
+                                {% if anonymize %}
+                                <span class="address-link">{{ mint.burner }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/account/{{ mint.burner }}" 
+                                   target="_blank" class="address-link">
+                                    {{ mint.burner | truncate(length=12) }}
+                                </a>
+                                {% endif %}
+                            </td>
+                            <td class="amount" data-sort-value="{{ mint.minted_amount }}">{{ mint.minted_amount | format_amount }}</td>
+                            <td>
+                                {% if anonymize %}
+                                <span class="address-link">{{ mint.signature }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/tx/{{ mint.signature }}" 
+                                   target="_blank" class="address-link">
+                                    {{ mint.signature | truncate(length=12) }}
+                                </a>
+                                {% endif %}
+                            </td>
+                            <td>
+                                {% if anonymize %}
+                                <span class="address-link">{{ mint.minted_signature }}</span>
+                                {% else %}
+                                <a href="{{ x1_explorer_base }}/tx/{{ mint.minted_signature }}" 
+                                   target="_blank" class="address-link">
+                                    {{ mint.minted_signature | truncate(length=12) }}
+                                </a>
+                                {% endif %}
+                            </td>
+                        </tr>
+                        {% endfor %}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+        {% endif %}
+
         <!-- Statistics Section -->
         <div class="stats-section">
             <div class="stats-container">
                 <div class="stats-grid">
                     <div class="stat-item burn">
                         <div class="stat-icon"><i class="fas fa-fire"></i></div>
-                        <div class="stat-label">Total Burned (Solana)</div>
-                        <div class="stat-value">{{ stats.total_burned_amount | round(precision=2) }}</div>
+                        <div class="stat-label">Total Burned ({{ source_chain_name }})</div>
+                        <div class="stat-value">{{ stats.total_burned_amount | format_amount }}</div>
                     </div>
                     <div class="stat-item mint">
                         <div class="stat-icon"><i class="fas fa-coins"></i></div>
-                        <div class="stat-label">Total Minted (X1)</div>
-                        <div class="stat-value">{{ stats.total_minted_amount | round(precision=2) }}</div>
+                        <div class="stat-label">Total Minted ({{ dest_chain_name }})</div>
+                        <div class="stat-value">{{ stats.total_minted_amount | format_amount }}</div>
                     </div>
                     <div class="stat-item pending">
                         <div class="stat-icon"><i class="fas fa-clock"></i></div>
-                        <div class="stat-label">Pending Mints (X1)</div>
+                        <div class="stat-label">Pending Mints ({{ dest_chain_name }})</div>
                         <div class="stat-value">{{ stats.pending_mints }}</div>
                     </div>
                     <div class="stat-item wallet">
@@ -480,10 +1136,112 @@ impl<'a> HtmlGenerator<'a> {
                         <div class="stat-label">Unique Wallets</div>
                         <div class="stat-value">{{ stats.unique_wallets }}</div>
                     </div>
+                    <div class="stat-item mint">
+                        <div class="stat-icon"><i class="fas fa-check-circle"></i></div>
+                        <div class="stat-label">Successful Mints</div>
+                        <div class="stat-value">{{ stats.successful_mints }}</div>
+                    </div>
+                    <div class="stat-item failed">
+                        <div class="stat-icon"><i class="fas fa-times-circle"></i></div>
+                        <div class="stat-label">Failed Mints</div>
+                        <div class="stat-value">{{ stats.failed_mints }}</div>
+                    </div>
+                    <div class="stat-item wallet">
+                        <div class="stat-icon"><i class="fas fa-percentage"></i></div>
+                        <div class="stat-label">Success Rate</div>
+                        <div class="stat-value">{% if stats.success_rate is number %}{{ stats.success_rate | round(precision=1) }}%{% else %}N/A{% endif %}</div>
+                    </div>
+                    <div class="stat-item pending">
+                        <div class="stat-icon"><i class="fas fa-gas-pump"></i></div>
+                        <div class="stat-label">Mint Fees (SOL)</div>
+                        <div class="stat-value">{{ stats.total_fees | format_amount(decimals=6) }}</div>
+                    </div>
                 </div>
             </div>
         </div>
 
+        <!-- Daily Activity Chart -->
+        <div class="results">
+            <div class="result-header">
+                <h2><i class="fas fa-chart-line"></i> Daily Activity</h2>
+                <span class="result-type">{{ chart_data.labels | length }} days</span>
+            </div>
+            <div class="chart-container">
+                <canvas id="activityChart"></canvas>
+            </div>
+        </div>
+
+        <!-- Top Burners Leaderboard -->
+        <div class="results">
+            <div class="result-header">
+                <h2><i class="fas fa-trophy"></i> Top Burners</h2>
+                <span class="result-type">Top {{ top_burners | length }}</span>
+            </div>
+            <div class="table-container">
+                <table>
+                    <thead>
+                        <tr>
+                            <th data-sort-type="number">Rank</th>
+                            <th data-sort-type="string">Wallet Address</th>
+                            <th data-sort-type="number">Total Burned ({{ source_chain_name }})</th>
+                            <th data-sort-type="number">Burns</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {% for wallet in top_burners %}
+                        <tr>
+                            <td data-sort-value="{{ wallet.rank }}">#{{ wallet.rank }}</td>
+                            <td data-sort-value="{{ wallet.wallet_address }}">
+                                {% if anonymize %}
+                                <span class="address-link">{{ wallet.wallet_address }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/account/{{ wallet.wallet_address }}" 
+                                   target="_blank" class="address-link">
+                                    {{ wallet.wallet_address | truncate(length=12) }}
+                                </a>
+                                {% endif %}
+                            </td>
+                            <td class="amount" data-sort-value="{{ wallet.total_burned }}">{{ wallet.total_burned | format_amount }}</td>
+                            <td>{{ wallet.burn_count }}</td>
+                        </tr>
+                        {% endfor %}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+
+        <!-- Daily Totals Section -->
+        <div class="results">
+            <div class="result-header">
+                <h2><i class="fas fa-calendar-day"></i> Daily Totals</h2>
+                <span class="result-type">{{ daily_totals | length }} days</span>
+            </div>
+            <div class="table-container">
+                <table>
+                    <thead>
+                        <tr>
+                            <th data-sort-type="date">Date</th>
+                            <th data-sort-type="number">Burned ({{ source_chain_name }})</th>
+                            <th data-sort-type="number">Burns</th>
+                            <th data-sort-type="number">Minted ({{ dest_chain_name }})</th>
+                            <th data-sort-type="number">Mints</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {% for day in daily_totals %}
+                        <tr>
+                            <td data-sort-value="{{ day.date }}">{{ day.date }}</td>
+                            <td class="amount" data-sort-value="{{ day.burned }}">{{ day.burned | format_amount }}</td>
+                            <td>{{ day.burn_count }}</td>
+                            <td class="amount" data-sort-value="{{ day.minted }}">{{ day.minted | format_amount }}</td>
+                            <td>{{ day.mint_count }}</td>
+                        </tr>
+                        {% endfor %}
+                    </tbody>
+                </table>
+            </div>
+        </div>
+
         <!-- Wallet Summary Section -->
         <div class="results">
             <div class="result-header">
@@ -494,24 +1252,33 @@ impl<'a> HtmlGenerator<'a> {
                 <table>
                     <thead>
                         <tr>
-                            <th>Wallet Address</th>
-                            <th>Total Burned (Solana)</th>
-                            <th>Total Minted (X1)</th>
-                            <th>Transactions</th>
-                            <th>Status</th>
+                            <th data-sort-type="string">Wallet Address</th>
+                            <th data-sort-type="number">Total Burned ({{ source_chain_name }})</th>
+                            <th data-sort-type="number">Total Minted ({{ dest_chain_name }})</th>
+                            <th data-sort-type="number">Transactions</th>
+                            <th data-sort-type="string">Status</th>
                         </tr>
                     </thead>
                     <tbody>
                         {% for wallet in wallet_summaries %}
                         <tr>
-                            <td>
-                                <a href="https://solscan.io/account/{{ wallet.wallet_address }}" 
+                            <td data-sort-value="{{ wallet.wallet_address }}">
+                                {% if anonymize %}
+                                <span class="address-link">{{ wallet.wallet_address }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/account/{{ wallet.wallet_address }}" 
                                    target="_blank" class="address-link">
                                     {{ wallet.wallet_address | truncate(length=12) }}
                                 </a>
+                                {% endif %}
+                                {% if wallet.page %}
+                                <a href="{{ wallet.page }}" class="address-link" title="All transactions of this wallet">
+                                    <i class="fas fa-file-lines"></i>
+                                </a>
+                                {% endif %}
                             </td>
-                            <td class="amount">{{ wallet.total_burned | round(precision=2) }}</td>
-                            <td class="amount">{{ wallet.total_minted | round(precision=2) }}</td>
+                            <td class="amount" data-sort-value="{{ wallet.total_burned }}">{{ wallet.total_burned | format_amount }}</td>
+                            <td class="amount" data-sort-value="{{ wallet.total_minted }}">{{ wallet.total_minted | format_amount }}</td>
                             <td>{{ wallet.burn_count }}</td>
                             <td>
                                 {% if wallet.mint_count > 0 %}
@@ -531,7 +1298,7 @@ impl<'a> HtmlGenerator<'a> {
         <div class="results">
             <div class="result-header">
                 <h2><i class="fas fa-list"></i> Transaction Records</h2>
-                <span class="result-type">{{ records | length }} transactions</span>
+                <span class="result-type">{{ record_count }} transactions</span>
             </div>
             
             <div class="search-container">
@@ -545,30 +1312,48 @@ impl<'a> HtmlGenerator<'a> {
                 <table id="recordsTable">
                     <thead>
                         <tr>
-                            <th>Time</th>
-                            <th>Wallet</th>
-                            <th>Amount</th>
-                            <th>Solana Tx</th>
-                            <th>Status</th>
-                            <th>X1 Tx</th>
+                            <th data-sort-type="date">Time</th>
+                            <th data-sort-type="string">Wallet</th>
+                            <th data-sort-type="number">Amount</th>
+                            <th data-sort-type="string">Solana Tx</th>
+                            <th data-sort-type="string">Status</th>
+                            <th data-sort-type="string">X1 Tx</th>
                         </tr>
                     </thead>
                     <tbody>
                         {% for record in records %}
                         <tr>
-                            <td>{{ record.timestamp }}</td>
-                            <td>
-                                <a href="https://solscan.io/account/{{ record.burner }}" 
+                            {% if record.timestamp_is_fallback %}
+                            <td data-sort-value="{{ record.timestamp_sort }}" class="fallback-time"
+                                title="Burn time unknown; showing when the record was created">{{ record.timestamp }} (created)</td>
+                            {% else %}
+                            <td data-sort-value="{{ record.timestamp_sort }}">{{ record.timestamp }}</td>
+                            {% endif %}
+                            <td data-sort-value="{{ record.burner }}">
+                                {% if anonymize %}
+                                <span class="address-link">{{ record.burner }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/account/{{ record.burner }}" 
                                    target="_blank" class="address-link">
                                     {{ record.burner | truncate(length=12) }}
                                 </a>
+                                {% endif %}
                             </td>
-                            <td class="amount">{{ record.amount_decimal | round(precision=2) }}</td>
-                            <td>
-                                <a href="https://solscan.io/tx/{{ record.signature }}" 
+                            <td class="amount" data-sort-value="{{ record.amount_raw }}">{{ record.amount_display }}</td>
+                            <td data-sort-value="{{ record.signature }}">
+                                {% if anonymize %}
+                                <span class="address-link">{{ record.signature }}</span>
+                                {% else %}
+                                <a href="{{ solana_explorer_base }}/tx/{{ record.signature }}" 
                                    target="_blank" class="address-link">
                                     {{ record.signature | truncate(length=12) }}
                                 </a>
+                                {% endif %}
+                                {% if record.memo_checked == "verified" %}
+                                <i class="fas fa-circle-check memo-check verified" title="Memo verified"></i>
+                                {% elif record.memo_checked == "rejected" %}
+                                <i class="fas fa-circle-xmark memo-check rejected" title="Memo rejected"></i>
+                                {% endif %}
                             </td>
                             <td>
                                 {% if record.is_minted %}
@@ -579,10 +1364,14 @@ impl<'a> HtmlGenerator<'a> {
                             </td>
                             <td>
                                 {% if record.minted_signature %}
-                                <a href="https://explorer.x1-testnet.xen.network/tx/{{ record.minted_signature }}" 
+                                {% if anonymize %}
+                                <span class="address-link">{{ record.minted_signature }}</span>
+                                {% else %}
+                                <a href="{{ x1_explorer_base }}/tx/{{ record.minted_signature }}" 
                                    target="_blank" class="address-link">
                                     {{ record.minted_signature | truncate(length=12) }}
                                 </a>
+                                {% endif %}
                                 {% else %}
                                 <span class="status-badge pending">Waiting</span>
                                 {% endif %}
@@ -592,39 +1381,369 @@ impl<'a> HtmlGenerator<'a> {
                     </tbody>
                 </table>
             </div>
+            
+            <div class="pager" id="recordsPager">
+                <button type="button" id="recordsPrev" onclick="changeRecordsPage(-1)">&laquo; Prev</button>
+                <span id="recordsPageInfo">{{ records | length }} of {{ record_count }} records</span>
+                <button type="button" id="recordsNext" onclick="changeRecordsPage(1)">Next &raquo;</button>
+            </div>
+            <script type="application/json" id="recordsData">{{ records_json | safe }}</script>
         </div>
     </main>
 
     <footer class="footer">
         <div class="footer-content">
-            <p>solXEN is The Second Best - Last updated: {{ last_updated }}</p>
-            <p>Powered by Rust and X1 Testnet</p>
+            <p>{{ report_title }} - Last updated: {{ last_updated }}</p>
+            <p>Powered by Rust and {{ dest_chain_name }}{% if network_label %} {{ network_label }}{% endif %}</p>
         </div>
     </footer>
 
     <script>
+        // The records table is paged from the JSON island: search and sort run
+        // over every record, and only the current page is turned into rows
+        const recordsPageSize = {{ records_page_size }};
+        const allRecords = JSON.parse(document.getElementById('recordsData').textContent);
+        const recordsSortKeys = ['ts', 'w', 'a', 's', 'm', 'x'];
+        let visibleRecords = allRecords;
+        let recordsPage = 0;
+
+        function escapeHtml(text) {
+            return String(text).replace(/[&<>"']/g, c => ({ '&': '&amp;', '<': '&lt;', '>': '&gt;', '"': '&quot;', "'": '&#39;' }[c]));
+        }
+
+        function truncate(text) {
+            return text.length > 12 ? text.slice(0, 12) + '…' : text;
+        }
+
+        function explorerLink(base, path, value) {
+            {% if anonymize %}
+            return `<span class="address-link">${escapeHtml(value)}</span>`;
+            {% else %}
+            return `<a href="${base}/${path}/${escapeHtml(value)}" target="_blank" class="address-link">${escapeHtml(truncate(value))}</a>`;
+            {% endif %}
+        }
+
+        function memoCheck(state) {
+            if (state === 'verified') return ' <i class="fas fa-circle-check memo-check verified" title="Memo verified"></i>';
+            if (state === 'rejected') return ' <i class="fas fa-circle-xmark memo-check rejected" title="Memo rejected"></i>';
+            return '';
+        }
+
+        function recordRow(r) {
+            const time = r.f
+                ? `<td class="fallback-time" title="Burn time unknown; showing when the record was created">${escapeHtml(r.t)} (created)</td>`
+                : `<td>${escapeHtml(r.t)}</td>`;
+            const status = r.m
+                ? '<span class="status-badge success">Minted</span>'
+                : '<span class="status-badge pending">Pending</span>';
+            const mintTx = r.x
+                ? explorerLink('{{ x1_explorer_base }}', 'tx', r.x)
+                : '<span class="status-badge pending">Waiting</span>';
+            return `<tr>${time}<td>${explorerLink('{{ solana_explorer_base }}', 'account', r.w)}</td>`
+                + `<td class="amount">${escapeHtml(r.ad)}</td><td>${explorerLink('{{ solana_explorer_base }}', 'tx', r.s)}${memoCheck(r.v)}</td>`
+                + `<td>${status}</td><td>${mintTx}</td></tr>`;
+        }
+
+        function renderRecords() {
+            const pages = Math.max(1, Math.ceil(visibleRecords.length / recordsPageSize));
+            recordsPage = Math.min(Math.max(recordsPage, 0), pages - 1);
+            const start = recordsPage * recordsPageSize;
+            const rows = visibleRecords.slice(start, start + recordsPageSize);
+
+            document.querySelector('#recordsTable tbody').innerHTML = rows.map(recordRow).join('');
+            document.getElementById('recordsPageInfo').textContent = visibleRecords.length === 0
+                ? 'No matching records'
+                : `Page ${recordsPage + 1} of ${pages} (${visibleRecords.length} records)`;
+            document.getElementById('recordsPrev').disabled = recordsPage === 0;
+            document.getElementById('recordsNext').disabled = recordsPage >= pages - 1;
+        }
+
+        function changeRecordsPage(step) {
+            recordsPage += step;
+            renderRecords();
+        }
+
         function searchRecords() {
-            const input = document.getElementById('searchInput');
-            const filter = input.value.toUpperCase();
-            const table = document.getElementById('recordsTable');
-            const rows = table.getElementsByTagName('tr');
-
-            for (let i = 1; i < rows.length; i++) {
-                const cells = rows[i].getElementsByTagName('td');
-                let found = false;
-                
-                for (let j = 0; j < cells.length; j++) {
-                    if (cells[j].textContent.toUpperCase().indexOf(filter) > -1) {
-                        found = true;
-                        break;
-                    }
-                }
-                
-                rows[i].style.display = found ? '' : 'none';
+            const filter = document.getElementById('searchInput').value.toUpperCase();
+            visibleRecords = filter === ''
+                ? allRecords.slice()
+                : allRecords.filter(r => [r.t, r.w, r.ad, r.s, r.x || '', r.m ? 'MINTED' : 'PENDING']
+                    .some(value => value.toUpperCase().indexOf(filter) > -1));
+            recordsPage = 0;
+            renderRecords();
+        }
+
+        function sortRecords(index, type, ascending) {
+            const key = recordsSortKeys[index];
+            const value = r => {
+                const raw = r[key];
+                if (type === 'number') return parseFloat(raw);
+                if (type === 'date') { const date = Date.parse(raw); return isNaN(date) ? -Infinity : date; }
+                return String(raw === null ? '' : raw).toLowerCase();
+            };
+            visibleRecords.sort((a, b) => {
+                const x = value(a);
+                const y = value(b);
+                if (x < y) return ascending ? -1 : 1;
+                if (x > y) return ascending ? 1 : -1;
+                return 0;
+            });
+            recordsPage = 0;
+            renderRecords();
+        }
+
+        renderRecords();
+
+        function sortKey(cell, type) {
+            const raw = cell.dataset.sortValue !== undefined ? cell.dataset.sortValue : cell.textContent.trim();
+            if (type === 'number') {
+                const value = parseFloat(raw);
+                return isNaN(value) ? -Infinity : value;
+            }
+            if (type === 'date') {
+                const value = Date.parse(raw);
+                return isNaN(value) ? -Infinity : value;
             }
+            return raw.toLowerCase();
+        }
+
+        function sortTable(header) {
+            const table = header.closest('table');
+            const tbody = table.tBodies[0];
+            const index = Array.from(header.parentNode.children).indexOf(header);
+            const type = header.dataset.sortType;
+            const ascending = !header.classList.contains('sort-asc');
+
+            table.querySelectorAll('th').forEach(th => th.classList.remove('sort-asc', 'sort-desc'));
+            header.classList.add(ascending ? 'sort-asc' : 'sort-desc');
+
+            if (table.id === 'recordsTable') {
+                sortRecords(index, type, ascending);
+                return;
+            }
+
+            const rows = Array.from(tbody.rows);
+            rows.sort((a, b) => {
+                const x = sortKey(a.cells[index], type);
+                const y = sortKey(b.cells[index], type);
+                if (x < y) return ascending ? -1 : 1;
+                if (x > y) return ascending ? 1 : -1;
+                return 0;
+            });
+            rows.forEach(row => tbody.appendChild(row));
+        }
+
+        document.querySelectorAll('th[data-sort-type]').forEach(header => {
+            header.addEventListener('click', () => sortTable(header));
+        });
+
+        const chartData = {{ chart_data | json_encode() | safe }};
+        if (window.Chart) {
+            new Chart(document.getElementById('activityChart'), {
+                type: 'line',
+                data: {
+                    labels: chartData.labels,
+                    datasets: [
+                        { label: 'Burned (' + {{ source_chain_name | json_encode() | safe }} + ')', data: chartData.burned, borderColor: '#27ae60', backgroundColor: 'rgba(39, 174, 96, 0.15)', fill: true, tension: 0.2 },
+                        { label: 'Minted (' + {{ dest_chain_name | json_encode() | safe }} + ')', data: chartData.minted, borderColor: '#3498db', backgroundColor: 'rgba(52, 152, 219, 0.15)', fill: true, tension: 0.2 }
+                    ]
+                },
+                options: {
+                    responsive: true,
+                    maintainAspectRatio: false,
+                    interaction: { mode: 'index', intersect: false },
+                    scales: { y: { beginAtZero: true } }
+                }
+            });
         }
     </script>
 </body>
 </html>"#.to_string()
     }
-}
\ No newline at end of file
+}
+
+/// Human readable summary of the record filter's constraints, or None when
+/// it has none
+fn describe_filter(filter: &RecordFilter, token_symbol: &str) -> Option<String> {
+    let solxen = |raw: u64| (Decimal::from(raw) / Decimal::from(1_000_000)).normalize();
+    let mut parts = Vec::new();
+    if let Some(min_amount) = filter.min_amount {
+        parts.push(format!("amount at least {} {}", solxen(min_amount), token_symbol));
+    }
+    if let Some(max_amount) = filter.max_amount {
+        parts.push(format!("amount at most {} {}", solxen(max_amount), token_symbol));
+    }
+    if let Some(status) = filter.status {
+        parts.push(format!("status {}", status.as_str()));
+    }
+    if parts.is_empty() { None } else { Some(parts.join(", ")) }
+}
+
+/// `--anonymize`: a short stand-in for an address or signature, `abcd…wxyz`
+/// taken from its hash, so the same wallet keeps the same label across the
+/// report without the label leading back to it
+fn anonymize(value: &str) -> String {
+    let hash = solana_sdk::hash::hash(value.as_bytes()).to_string();
+    format!("{}…{}", &hash[..4], &hash[hash.len() - 4..])
+}
+
+/// Mask everything in a record that can be looked up on an explorer; the
+/// burn signature leads straight to the burner, the mint signature to the recipient
+fn anonymize_record(record: &mut BurnRecord) {
+    record.burner = anonymize(&record.burner);
+    record.signature = anonymize(&record.signature);
+    record.minted_signature = record.minted_signature.as_deref().map(anonymize);
+    record.memo = None;
+}
+
+/// File name of a wallet's page. Addresses are base58, so anything else could
+/// escape the wallet directory and gets no page.
+fn wallet_page_name(address: &str) -> Option<String> {
+    let safe = !address.is_empty() && address.len() <= 64 && address.chars().all(|c| c.is_ascii_alphanumeric());
+    safe.then(|| format!("{}.html", address))
+}
+
+/// A burn record as the report tables show it
+fn template_record(record: BurnRecord) -> TemplateBurnRecord {
+    // Calculate values first to avoid partial moves
+    let amount_decimal = record.amount_as_decimal();
+    let amount_display = group_thousands(amount_decimal, 2);
+    // Without a burn time fall back to when the record was created
+    let timestamp_is_fallback = record.timestamp.is_none();
+    let timestamp = record.timestamp.unwrap_or(record.created_at);
+    let timestamp_str = Some(timestamp.format("%Y-%m-%d %H:%M").to_string());
+    let timestamp_sort = Some(timestamp.to_rfc3339());
+    let created_at_str = record.created_at.format("%Y-%m-%d %H:%M").to_string();
+    let minted_time_str = record.minted_time.map(|t| t.format("%Y-%m-%d %H:%M").to_string());
+    
+    TemplateBurnRecord {
+        id: record.id,
+        signature: record.signature,
+        burner: record.burner,
+        amount_raw: record.amount,
+        amount_decimal,
+        amount_display,
+        memo: record.memo,
+        token: record.token,
+        timestamp: timestamp_str,
+        timestamp_sort,
+        timestamp_is_fallback,
+        memo_checked: record.memo_checked,
+        created_at: created_at_str,
+        is_minted: record.is_minted,
+        minted_time: minted_time_str,
+        minted_signature: record.minted_signature,
+    }
+}
+
+/// The records-table JSON island, safe to embed in a <script> element
+fn records_json(records: &[TemplateBurnRecord]) -> Result<String> {
+    let rows = records.iter().map(|record| RecordsTableRow {
+        t: record.timestamp.as_deref().unwrap_or_default(),
+        ts: record.timestamp_sort.as_deref().unwrap_or_default(),
+        f: record.timestamp_is_fallback,
+        w: &record.burner,
+        a: record.amount_raw,
+        ad: &record.amount_display,
+        s: &record.signature,
+        m: record.is_minted,
+        x: record.minted_signature.as_deref(),
+        v: record.memo_checked,
+    }).collect::<Vec<_>>();
+    // "</" inside the JSON would end the surrounding <script> element
+    Ok(serde_json::to_string(&rows)?.replace("</", "<\\/"))
+}
+
+/// Tera filter rendering an amount with thousands separators and a fixed
+/// number of decimals (`decimals`, default 2): 1234567.891 -> "1,234,567.89".
+/// Takes a number or a decimal string; strings are formatted exactly.
+fn format_amount(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let amount = match value {
+        Value::String(amount) => Decimal::from_str(amount).ok(),
+        // Large or tiny floats print in exponent form
+        Value::Number(amount) => {
+            let amount = amount.to_string();
+            Decimal::from_str(&amount).or_else(|_| Decimal::from_scientific(&amount)).ok()
+        }
+        _ => None,
+    }
+    .ok_or_else(|| tera::Error::msg(format!("format_amount expects a number, got {}", value)))?;
+    let decimals = args.get("decimals").and_then(Value::as_u64).unwrap_or(2) as u32;
+    Ok(Value::String(group_thousands(amount, decimals)))
+}
+
+/// `amount` rounded to `decimals` decimals, with commas between thousands
+fn group_thousands(amount: Decimal, decimals: u32) -> String {
+    let rounded = amount.round_dp(decimals);
+    let formatted = format!("{:.*}", decimals as usize, rounded.abs());
+    let (integer, fraction) = formatted.split_once('.').map_or((formatted.as_str(), None), |(i, f)| (i, Some(f)));
+    
+    let mut grouped = String::with_capacity(formatted.len() + integer.len() / 3 + 1);
+    if rounded.is_sign_negative() && !rounded.is_zero() {
+        grouped.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+    grouped
+}
+
+/// Write via a temp file in the same directory and rename it into place, so
+/// readers see either the old file or the complete new one
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Output path has no file name: {}", path.display()))?;
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+    
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if !parent.is_dir() {
+            return Err(anyhow::anyhow!("Output directory does not exist: {}", parent.display()));
+        }
+    }
+    
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn amounts_past_2_pow_53_stay_exact() {
+        let amount = 9_007_199_254_740_993; // 2^53 + 1, the first integer an f64 can't hold
+        let record = template_record(BurnRecord {
+            id: Some(1),
+            signature: "burn-1".to_string(),
+            burner: "wallet-a".to_string(),
+            amount,
+            memo: None,
+            token: None,
+            timestamp: None,
+            memo_checked: MemoCheck::Unchecked,
+            created_at: Utc::now(),
+            is_minted: false,
+            minted_time: None,
+            minted_signature: None,
+            minted_amount: None,
+        });
+        assert_eq!(record.amount_raw, amount);
+        assert_eq!(record.amount_decimal, Decimal::from_str("9007199254.740993").unwrap());
+        assert_eq!(record.amount_display, "9,007,199,254.74");
+        
+        let json = records_json(&[record]).unwrap();
+        assert!(json.contains(r#""a":9007199254740993,"ad":"9,007,199,254.74""#), "{}", json);
+        let rows: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(rows[0]["a"].as_u64(), Some(amount));
+        assert_eq!(rows[0]["ad"], "9,007,199,254.74");
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+use log::info;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::config::Config;
+use crate::database::Database;
+
+/// Exclusive lock held while a process mints, so overlapping runs (a cron
+/// firing while the previous run is still busy) can't mint the same pending
+/// burns twice. The OS drops the lock when the file closes, also on a crash,
+/// so a stale lock file never blocks the next run.
+pub struct InstanceLock {
+    _file: File,
+}
+
+impl InstanceLock {
+    /// Take the lock at `lock_path` (SOLXEN_LOCK_PATH, --lock-path) or next to
+    /// the destination database. An in-memory database is private to this
+    /// process and needs no lock, so None is returned.
+    pub fn acquire(config: &Config) -> Result<Option<Self>> {
+        let path = match config.lock_path.clone().or_else(|| Database::default_lock_path(&config.database_url)) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        Self::acquire_at(&path).map(Some)
+    }
+    
+    fn acquire_at(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Not truncated on open: the holder's pid stays readable until we own the lock
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| anyhow::anyhow!("Failed to open lock file {}: {}", path.display(), e))?;
+        
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                let mut holder = String::new();
+                file.read_to_string(&mut holder).ok();
+                match holder.trim() {
+                    "" => bail!("Another run is in progress (lock held on {})", path.display()),
+                    pid => bail!("Another run is in progress (pid {}, lock held on {})", pid, path.display()),
+                }
+            }
+            Err(TryLockError::Error(e)) => {
+                bail!("Failed to lock {}: {}", path.display(), e)
+            }
+        }
+        
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        writeln!(file, "{}", std::process::id())?;
+        info!("🔒 Acquired run lock {}", path.display());
+        Ok(InstanceLock { _file: file })
+    }
+}
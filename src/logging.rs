@@ -0,0 +1,50 @@
+use chrono::{SecondsFormat, Utc};
+use clap::ValueEnum;
+use env_logger::fmt::Formatter;
+use log::Record;
+use std::future::Future;
+use std::io::Write;
+
+/// How log lines are written to stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable lines (env_logger's default)
+    Text,
+    /// One JSON object per line, for log aggregators
+    Json,
+}
+
+/// The burn a mint worker is processing, attached to its JSON log lines
+struct MintContext {
+    burner: String,
+    signature: String,
+}
+
+tokio::task_local! {
+    static MINT_CONTEXT: MintContext;
+}
+
+/// Run `future` with every log line it emits tagged with the burn's burner and
+/// signature (in JSON output)
+pub async fn with_mint_context<F: Future>(burner: &str, signature: &str, future: F) -> F::Output {
+    let context = MintContext {
+        burner: burner.to_string(),
+        signature: signature.to_string(),
+    };
+    MINT_CONTEXT.scope(context, future).await
+}
+
+/// env_logger format writing each record as a single-line JSON object
+pub fn format_json(buf: &mut Formatter, record: &Record) -> std::io::Result<()> {
+    let mut line = serde_json::json!({
+        "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "message": record.args().to_string(),
+    });
+    let _ = MINT_CONTEXT.try_with(|context| {
+        line["burner"] = context.burner.clone().into();
+        line["signature"] = context.signature.clone().into();
+    });
+    writeln!(buf, "{}", line)
+}
@@ -1,23 +1,157 @@
+// RpcPool::call closures return solana_client's ClientError, which is large but not ours to box
+#![allow(clippy::result_large_err)]
+
 use anyhow::Result;
-use clap::{Parser, Subcommand};
-use log::{error, info};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use clap::{ArgAction, Args, Parser, Subcommand};
+use comfy_table::presets::UTF8_FULL_CONDENSED;
+use comfy_table::{Cell, CellAlignment, Table};
+use log::{error, info, LevelFilter};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::io::IsTerminal;
+use std::net::SocketAddr;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::signal::unix::{signal, Signal, SignalKind};
 
+mod api;
 mod config;
 mod database;
+mod health;
+mod hooks;
 mod html;
+mod lock;
+mod logging;
+mod metrics;
 mod migrator;
 mod minter;
+mod progress;
+mod rpc;
 mod types;
+mod verifier;
+mod webhook;
 
-use config::Config;
+use config::{parse_timezone, Config, DedupeRule, Network, Theme};
 use database::Database;
-use migrator::DatabaseMigrator;
+use lock::InstanceLock;
+use logging::LogFormat;
+use metrics::MetricsServer;
+use migrator::{DatabaseMigrator, MigrationReport};
+use minter::MintSummary;
+use types::{BurnRecord, RecordFilter, RecordStatus, Statistics};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    
+    /// Expose Prometheus metrics on this address (e.g. 127.0.0.1:9100) while running
+    #[arg(long, global = true)]
+    metrics_addr: Option<SocketAddr>,
+    
+    /// X1 network to mint on: sets the RPC URL, explorer and token mint
+    /// defaults, overriding SOLXEN_NETWORK [default: x1-testnet]
+    #[arg(long, global = true, value_enum)]
+    network: Option<Network>,
+    
+    /// X1 RPC endpoint, overriding the network's and SOLXEN_RPC_URL; repeat
+    /// or separate with commas to fail over between several, in order
+    #[arg(long = "rpc-url", global = true, value_name = "URL", value_delimiter = ',')]
+    rpc_urls: Vec<String>,
+    
+    /// Token mint address, overriding the network's and SOLXEN_TOKEN_MINT
+    #[arg(long, global = true, value_name = "ADDRESS")]
+    token_mint: Option<String>,
+    
+    /// Destination database URL, overriding SOLXEN_DATABASE_URL
+    /// (e.g. sqlite:campaigns/second.db or postgres://...)
+    #[arg(long = "database", global = true, value_name = "URL")]
+    database_url: Option<String>,
+    
+    /// Log more detail: -v for debug, -vv for trace (RUST_LOG still takes precedence)
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+    
+    /// Log less: -q for warnings and errors only, -qq for errors only
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    quiet: u8,
+    
+    /// Log line format; json writes one object per line with level, target,
+    /// message and, while minting, the burner and burn signature
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+    
+    /// Don't draw progress bars (they are also skipped when stderr is not a terminal)
+    #[arg(long, global = true)]
+    no_progress: bool,
+    
+    /// Initial theme of the generated report (visitors can still toggle it)
+    #[arg(long, global = true, value_enum)]
+    theme: Option<Theme>,
+    
+    /// Wallets listed in the report's Top Burners leaderboard [default: 10]
+    #[arg(long, global = true)]
+    top_n: Option<usize>,
+    
+    /// Latest mints listed in the report's Recent Activity section [default: 20]
+    #[arg(long, global = true)]
+    recent_n: Option<usize>,
+    
+    /// Exit non-zero when any mint fails, including in the pipeline where
+    /// failures are otherwise only logged (the report is still generated)
+    #[arg(long, global = true)]
+    fail_on_mint_error: bool,
+    
+    /// Only warn when the mint's on-chain decimals differ from the 6 every
+    /// amount is scaled by, instead of refusing to start (for simulations)
+    #[arg(long, global = true)]
+    skip_decimal_check: bool,
+    
+    /// Only mark a burn as minted once its mint transaction is finalized, not
+    /// just confirmed, so a fork can't drop a mint that was already recorded
+    #[arg(long, global = true)]
+    require_finalized: bool,
+    
+    /// Also write a page per wallet (wallet/<address>.html) with its full
+    /// transaction list, linked from the Wallet Summary table
+    #[arg(long, global = true)]
+    per_wallet_pages: bool,
+    
+    /// Directory the report artifacts (index.html, index.json) are written to,
+    /// created if missing [default: current directory]
+    #[arg(long, global = true)]
+    output_dir: Option<PathBuf>,
+    
+    /// Lock file that keeps two minting runs from overlapping, overriding
+    /// SOLXEN_LOCK_PATH [default: the database path with a .lock suffix]
+    #[arg(long, global = true, value_name = "PATH")]
+    lock_path: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Log level picked by -q/-v; info when neither is given
+    fn log_level(&self) -> LevelFilter {
+        match (self.quiet, self.verbose) {
+            (0, 0) => LevelFilter::Info,
+            (0, 1) => LevelFilter::Debug,
+            (0, _) => LevelFilter::Trace,
+            (1, _) => LevelFilter::Warn,
+            _ => LevelFilter::Error,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Check that the source database exists, the destination is writable,
+    /// the keypairs parse, the RPC responds and the token mint is a Token-2022
+    /// mint; exits non-zero when a check fails
+    Check,
 }
 
 #[derive(Subcommand)]
@@ -27,110 +161,782 @@ enum Commands {
         /// Only migrate the latest record for this specific burner address
         #[arg(long)]
         burner: Option<String>,
+        /// Skip burns smaller than this many solXEN (e.g. 420.69)
+        #[arg(long, value_parser = parse_solxen_amount)]
+        min_amount: Option<u64>,
+        /// Reject burns larger than this many solXEN as implausible (e.g. 1000000)
+        #[arg(long, value_parser = parse_solxen_amount)]
+        max_amount: Option<u64>,
+        /// Migrate burns above the max amount anyway
+        #[arg(long)]
+        force: bool,
+        /// Source burns.db to read; repeat to merge several shards [default: burn-data/burns.db]
+        #[arg(long = "source", value_name = "PATH")]
+        sources: Vec<PathBuf>,
+        /// Read burns from a CSV export (with a header row) instead of burns.db
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["sources", "burner", "source_table", "full"])]
+        source_csv: Option<PathBuf>,
+        /// Only migrate burns whose memo contains this text (burns without a memo are skipped)
+        #[arg(long)]
+        require_memo: Option<String>,
+        /// Skip burns whose memo contains this text
+        #[arg(long)]
+        exclude_memo: Option<String>,
+        /// Only migrate burns whose token column equals this value
+        #[arg(long)]
+        token: Option<String>,
+        /// Name of the burns table in the source databases [default: burns]
+        #[arg(long)]
+        source_table: Option<String>,
+        /// Rescan every source row instead of resuming from the last checkpoint
+        /// (needed after loosening the amount, memo or token filters)
+        #[arg(long)]
+        full: bool,
+        /// Read every source first and keep one row per signature, instead of
+        /// keeping whichever source is migrated first
+        #[arg(long, conflicts_with = "burner")]
+        dedupe: bool,
+        /// Row kept by --dedupe when sources disagree [default: highest-amount]
+        #[arg(long, value_enum, requires = "dedupe")]
+        dedupe_rule: Option<DedupeRule>,
+        /// Time zone of source timestamps written without an offset, as an
+        /// IANA name (e.g. Asia/Shanghai); overrides SOLXEN_SOURCE_TIMEZONE [default: UTC]
+        #[arg(long, value_parser = parse_timezone)]
+        source_timezone: Option<Tz>,
+        /// Print what the migration did as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Process minting operations
-    Mint,
+    Mint {
+        /// Only mint pending burns of this burner address
+        #[arg(long)]
+        burner: Option<String>,
+        /// Print the estimated SOL cost of minting the pending burns (fees
+        /// plus token account rent) without sending anything
+        #[arg(long)]
+        estimate: bool,
+        /// Show each pending burn and ask whether to mint it (y), skip it (n)
+        /// or stop (q); needs an interactive terminal and mints one at a time
+        #[arg(long, conflicts_with = "estimate")]
+        confirm_each: bool,
+        /// Write an unsigned transaction for every pending burn to this file
+        /// (one JSON object per line) for signing on another machine; nothing
+        /// is sent and no keypair is needed
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["estimate", "confirm_each", "submit"])]
+        build_only: Option<PathBuf>,
+        /// Broadcast the signed transactions of a --build-only file (with each
+        /// line's `transaction` filled in) and mark the burns as minted
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["estimate", "confirm_each", "burner"])]
+        submit: Option<PathBuf>,
+        #[command(flatten)]
+        mint: MintArgs,
+    },
+    /// Retry minting burns whose previous attempts failed
+    Remint {
+        /// Only retry failed mints for this burner address
+        #[arg(long)]
+        burner: Option<String>,
+        #[command(flatten)]
+        mint: MintArgs,
+    },
     /// Generate HTML report
-    Generate,
+    Generate {
+        /// Path of the HTML report (index.json is written next to it); takes
+        /// precedence over --output-dir
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Only report records created or minted after this RFC3339 time
+        /// (e.g. 2024-06-01T00:00:00Z); statistics are scoped the same way
+        #[arg(long, value_parser = parse_rfc3339)]
+        since: Option<DateTime<Utc>>,
+        /// Also write gzip-compressed copies (index.html.gz, index.json.gz)
+        /// next to the plain files, for hosts that serve pre-compressed files
+        #[arg(long)]
+        gzip: bool,
+        /// Only list burns of at least this many solXEN (e.g. 1000); statistics
+        /// and wallet totals still cover every burn
+        #[arg(long, value_parser = parse_solxen_amount)]
+        min_amount: Option<u64>,
+        /// Only list burns of at most this many solXEN
+        #[arg(long, value_parser = parse_solxen_amount)]
+        max_amount: Option<u64>,
+        /// Only list burns in this mint state; failed burns are pending burns
+        /// with at least one failed attempt
+        #[arg(long, value_enum)]
+        status: Option<RecordStatus>,
+        /// Show wallet addresses and signatures as short hashed labels
+        /// (e.g. 4Fq2…x9Tb) without explorer links, and skip the wallet pages;
+        /// totals are unchanged
+        #[arg(long)]
+        anonymize: bool,
+    },
     /// Run full pipeline (migrate -> mint -> generate)
     Run {
         /// Only migrate the latest record for this specific burner address
         #[arg(long)]
         burner: Option<String>,
+        #[command(flatten)]
+        mint: MintArgs,
+    },
+    /// Print database statistics to the terminal
+    Stats {
+        /// Print the statistics as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the most recent burn records as a table
+    List {
+        /// Show at most this many records, newest first
+        #[arg(long, default_value = "20")]
+        limit: NonZeroUsize,
+        /// Only show burns by this address
+        #[arg(long)]
+        burner: Option<String>,
+        /// Only show records in this state; failed means pending with at
+        /// least one failed attempt
+        #[arg(long, value_enum)]
+        status: Option<RecordStatus>,
+    },
+    /// Show every recorded mint attempt for a burn signature
+    Attempts {
+        /// Signature of the burn transaction
+        signature: String,
     },
+    /// Mark a minted burn as pending again so the next mint run reissues it.
+    /// Only the database changes; nothing is sent on chain.
+    Unmint {
+        /// Signature of the burn transaction
+        #[arg(long)]
+        signature: String,
+        /// Confirm the reset; without it nothing is changed
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Show how the source databases will be read (column types, amount and
+    /// timestamp interpretation, sample rows) without migrating anything
+    Doctor {
+        /// Source burns.db to inspect; repeatable [default: burn-data/burns.db]
+        #[arg(long = "source", value_name = "PATH")]
+        sources: Vec<PathBuf>,
+        /// Name of the burns table in the source databases [default: burns]
+        #[arg(long)]
+        source_table: Option<String>,
+    },
+    /// Check the databases, RPC and mint authority without changing anything;
+    /// exits non-zero when a check fails
+    Healthcheck,
+    /// Compare on-chain token balances of minted wallets against the database
+    Verify,
+    /// Inspect the configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Run the full pipeline repeatedly, picking up new burns as they appear
+    Watch {
+        /// Seconds to wait between pipeline cycles
+        #[arg(long, default_value_t = 300)]
+        interval_secs: u64,
+        /// Only migrate the latest record for this specific burner address
+        #[arg(long)]
+        burner: Option<String>,
+        #[command(flatten)]
+        mint: MintArgs,
+    },
+    /// Serve a read-only JSON API over the database
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        listen: SocketAddr,
+        /// Origin allowed to call the API from a browser; repeatable [default: any]
+        #[arg(long = "cors-origin", value_name = "ORIGIN")]
+        cors_origins: Vec<String>,
+    },
+}
+
+/// Options shared by every command that mints
+#[derive(Args)]
+struct MintArgs {
+    /// Number of records to mint in parallel [default: 1]
+    #[arg(long)]
+    concurrency: Option<NonZeroUsize>,
+    /// Seconds to wait for each mint transaction to confirm [default: 60]
+    #[arg(long)]
+    confirmation_timeout_secs: Option<u64>,
+    /// Milliseconds to pause after each mint at first; the pause grows when
+    /// the RPC rate limits and shrinks while it keeps up [default: 2000]
+    #[arg(long)]
+    mint_delay_ms: Option<u64>,
+    /// Shortest the pause shrinks to; 0 lets it drop away entirely [default: --mint-delay-ms]
+    #[arg(long)]
+    mint_delay_min_ms: Option<u64>,
+    /// Longest the pause grows to under rate limiting [default: 30000]
+    #[arg(long)]
+    mint_delay_max_ms: Option<u64>,
+    /// Only mint burns of at least this many solXEN (e.g. 420.69)
+    #[arg(long, value_parser = parse_solxen_amount)]
+    min_amount: Option<u64>,
+    /// Mint at most this many eligible records, burns without failed attempts first; the rest stay pending
+    #[arg(long)]
+    limit: Option<NonZeroUsize>,
+    /// Skip burns larger than this many solXEN as implausible (e.g. 1000000)
+    #[arg(long, value_parser = parse_solxen_amount)]
+    max_amount: Option<u64>,
+    /// Mint burns above the max amount anyway; remint also retries burns that
+    /// reached the max attempt cap
+    #[arg(long)]
+    force: bool,
+    /// Mint even when the fee payer's SOL balance looks too low for the run,
+    /// e.g. to use up what is left on a partial run
+    #[arg(long)]
+    ignore_balance: bool,
+}
+
+impl Commands {
+    /// Fold the subcommand's flags into `config`, before it is validated
+    fn apply(&self, config: &mut Config) -> Result<()> {
+        match self {
+            Commands::Migrate {
+                min_amount,
+                max_amount,
+                force,
+                sources,
+                require_memo,
+                exclude_memo,
+                token,
+                source_table,
+                full,
+                dedupe,
+                dedupe_rule,
+                source_timezone,
+                ..
+            } => {
+                if let Some(min_amount) = *min_amount {
+                    config.min_burn_amount = min_amount;
+                }
+                if max_amount.is_some() {
+                    config.max_burn_amount = *max_amount;
+                }
+                config.allow_above_max_burn = *force;
+                if !sources.is_empty() {
+                    config.source_db_paths = sources.clone();
+                }
+                if require_memo.is_some() {
+                    config.require_memo = require_memo.clone();
+                }
+                if exclude_memo.is_some() {
+                    config.exclude_memo = exclude_memo.clone();
+                }
+                if token.is_some() {
+                    config.source_token_filter = token.clone();
+                }
+                if let Some(source_table) = source_table {
+                    config.source_table = source_table.clone();
+                }
+                if *full {
+                    config.incremental_migration = false;
+                }
+                config.dedupe_sources = *dedupe;
+                if let Some(rule) = *dedupe_rule {
+                    config.dedupe_rule = rule;
+                }
+                if let Some(source_timezone) = *source_timezone {
+                    config.source_timezone = source_timezone;
+                }
+            }
+            Commands::Doctor { sources, source_table } => {
+                if !sources.is_empty() {
+                    config.source_db_paths = sources.clone();
+                }
+                if let Some(source_table) = source_table {
+                    config.source_table = source_table.clone();
+                }
+            }
+            Commands::Mint { confirm_each, mint, .. } => {
+                mint.apply(config);
+                config.confirm_each = *confirm_each;
+            }
+            Commands::Remint { mint, .. } | Commands::Run { mint, .. } | Commands::Watch { mint, .. } => {
+                mint.apply(config);
+            }
+            Commands::Generate { output, since, gzip, min_amount, max_amount, status, anonymize } => {
+                if let Some(output) = output {
+                    let file_name = output.file_name()
+                        .ok_or_else(|| anyhow::anyhow!("--output must name a file: {}", output.display()))?;
+                    config.html_file_name = file_name.to_string_lossy().into_owned();
+                    config.output_dir = output.parent().map(PathBuf::from).unwrap_or_default();
+                }
+                config.report_since = *since;
+                config.gzip_output = *gzip;
+                config.anonymize = *anonymize;
+                config.report_filter = RecordFilter {
+                    min_amount: *min_amount,
+                    max_amount: *max_amount,
+                    status: *status,
+                    since: None,
+                    burner: None,
+                };
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl MintArgs {
+    fn apply(&self, config: &mut Config) {
+        if let Some(concurrency) = self.concurrency {
+            config.mint_concurrency = concurrency.get();
+        }
+        if let Some(timeout) = self.confirmation_timeout_secs {
+            config.confirmation_timeout_secs = timeout;
+        }
+        if let Some(delay) = self.mint_delay_ms {
+            config.mint_delay_ms = delay;
+        }
+        if let Some(min) = self.mint_delay_min_ms {
+            config.mint_delay_min_ms = Some(min);
+        }
+        if let Some(max) = self.mint_delay_max_ms {
+            config.mint_delay_max_ms = max;
+        }
+        if let Some(min_amount) = self.min_amount {
+            config.min_burn_amount = min_amount;
+        }
+        if let Some(limit) = self.limit {
+            config.mint_limit = Some(limit.get());
+        }
+        if let Some(max_amount) = self.max_amount {
+            config.max_burn_amount = Some(max_amount);
+        }
+        config.allow_above_max_burn = self.force;
+        config.ignore_balance = self.ignore_balance;
+    }
+}
+
+fn parse_rfc3339(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| e.to_string())
+}
+
+/// Parse a solXEN amount such as `420.69` into raw units (6 decimals)
+fn parse_solxen_amount(value: &str) -> Result<u64, String> {
+    let amount = Decimal::from_str(value).map_err(|e| e.to_string())?;
+    if amount.is_sign_negative() {
+        return Err("amount cannot be negative".to_string());
+    }
+    let raw = amount * Decimal::from(1_000_000);
+    if !raw.fract().is_zero() {
+        return Err("solXEN has at most 6 decimal places".to_string());
+    }
+    raw.to_u64().ok_or_else(|| "amount is too large".to_string())
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    env_logger::init();
-    
     let cli = Cli::parse();
-    let config = Config::load()?;
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(cli.log_level()).parse_default_env();
+    if cli.log_format == LogFormat::Json {
+        builder.format(logging::format_json);
+    }
+    let logger = builder.build();
+    progress::init_logger(logger)?;
+    if cli.no_progress {
+        progress::disable_bars();
+    }
     
-    match cli.command {
-        Some(Commands::Migrate { burner }) => {
+    let mut config = Config::load()?;
+    if let Some(network) = cli.network {
+        config.set_network(network);
+    }
+    if !cli.rpc_urls.is_empty() {
+        config.x1_rpc_urls = cli.rpc_urls;
+    }
+    if let Some(token_mint) = cli.token_mint {
+        config.token_mint = token_mint;
+    }
+    if let Some(database_url) = cli.database_url {
+        config.database_url = database_url;
+    }
+    if let Some(theme) = cli.theme {
+        config.theme = theme;
+    }
+    if let Some(top_n) = cli.top_n {
+        config.top_burners = top_n;
+    }
+    if let Some(recent_n) = cli.recent_n {
+        config.recent_mints = recent_n;
+    }
+    config.per_wallet_pages = cli.per_wallet_pages;
+    config.fail_on_mint_error = cli.fail_on_mint_error;
+    config.skip_decimal_check = cli.skip_decimal_check;
+    if cli.require_finalized {
+        config.require_finalized_before_mark = true;
+    }
+    if let Some(output_dir) = cli.output_dir {
+        config.output_dir = output_dir;
+    }
+    if cli.lock_path.is_some() {
+        config.lock_path = cli.lock_path;
+    }
+    // Validate what the run will actually use, subcommand flags included
+    if let Some(command) = &cli.command {
+        command.apply(&mut config)?;
+    }
+    config.validate()?;
+    
+    let metrics_server = match cli.metrics_addr {
+        Some(addr) => Some(MetricsServer::start(addr).await?),
+        None => None,
+    };
+    
+    let result = run(cli.command, config).await;
+    
+    if let Some(server) = metrics_server {
+        server.shutdown().await;
+    }
+    result?;
+    
+    info!("Process completed successfully");
+    Ok(())
+}
+
+async fn run(command: Option<Commands>, config: Config) -> Result<()> {
+    match command {
+        Some(Commands::Migrate { burner, source_csv, json, .. }) => {
             info!("Starting data migration");
             let migrator = DatabaseMigrator::new(config);
-            migrator.migrate(burner.as_deref()).await?;
+            let report = match source_csv {
+                Some(path) => migrator.migrate_csv(&path).await?,
+                None => migrator.migrate(burner.as_deref()).await?,
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_migration_report(&report);
+            }
         }
-        Some(Commands::Mint) => {
-            info!("Starting minting process");
-            let db = Database::new(&config.database_url).await?;
+        Some(Commands::Doctor { .. }) => {
+            DatabaseMigrator::new(config).doctor()?;
+        }
+        Some(Commands::Mint { burner, estimate, confirm_each, build_only, submit, .. }) => {
+            if confirm_each && !std::io::stdin().is_terminal() {
+                anyhow::bail!("--confirm-each needs an interactive terminal to prompt on");
+            }
+            if confirm_each {
+                progress::disable_bars();
+            }
+            let db = Database::new(&config).await?;
+            if estimate {
+                info!("Estimating minting costs");
+                let minter = minter::TokenMinter::new(&config, &db).await?;
+                minter.estimate_pending_mints(burner.as_deref()).await?;
+            } else if let Some(path) = build_only {
+                info!("Building unsigned mint transactions");
+                let minter = minter::TokenMinter::new(&config, &db).await?;
+                minter.build_offline_mints(burner.as_deref(), &path).await?;
+            } else if let Some(path) = submit {
+                let _lock = InstanceLock::acquire(&config)?;
+                info!("Submitting signed mint transactions");
+                let minter = minter::TokenMinter::new(&config, &db).await?;
+                let summary = minter.submit_offline_mints(&path).await?;
+                summary.log();
+                check_mint_failures(&config, &summary)?;
+            } else {
+                let _lock = InstanceLock::acquire(&config)?;
+                info!("Starting minting process");
+                let mut minter = minter::TokenMinter::new(&config, &db).await?;
+                let summary = minter.process_pending_mints(burner.as_deref()).await?;
+                summary.log();
+                check_mint_failures(&config, &summary)?;
+            }
+        }
+        Some(Commands::Remint { burner, mint }) => {
+            info!("Retrying failed mints");
+            let _lock = InstanceLock::acquire(&config)?;
+            let db = Database::new(&config).await?;
             let mut minter = minter::TokenMinter::new(&config, &db).await?;
-            minter.process_pending_mints().await?;
+            let summary = minter.remint_failed(burner.as_deref(), mint.force).await?;
+            summary.log();
+            check_mint_failures(&config, &summary)?;
         }
-        Some(Commands::Generate) => {
+        Some(Commands::Generate { .. }) => {
             info!("Generating HTML report");
-            let db = Database::new(&config.database_url).await?;
-            let generator = html::HtmlGenerator::new(&db);
+            let db = Database::new(&config).await?;
+            let generator = html::HtmlGenerator::new(&config, &db);
             generator.generate().await?;
         }
-        Some(Commands::Run { burner }) => {
-            info!("Running full pipeline");
-            
-            // Step 1: Migrate data
-            info!("Step 1: Migrating data from burns.db");
-            let migrator = DatabaseMigrator::new(config.clone());
-            match migrator.migrate(burner.as_deref()).await {
-                Ok(count) => {
-                    info!("Migrated {} records", count);
-                }
-                Err(e) => {
-                    error!("Migration failed: {}", e);
-                    return Err(e);
-                }
+        Some(Commands::Stats { json }) => {
+            let db = Database::new(&config).await?;
+            let stats = db.get_statistics().await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else {
+                print_statistics(&stats);
             }
-            
-            let db = Database::new(&config.database_url).await?;
-            
-            // Step 2: Process minting
-            info!("Step 2: Processing minting operations");
-            let mut minter = minter::TokenMinter::new(&config, &db).await?;
-            if let Err(e) = minter.process_pending_mints().await {
-                error!("Minting failed: {}", e);
+        }
+        Some(Commands::List { limit, burner, status }) => {
+            let db = Database::new(&config).await?;
+            let filter = RecordFilter { status, burner, ..RecordFilter::default() };
+            let records = db.get_records_filtered(&filter, Some(limit.get() as i64), 0).await?;
+            if records.is_empty() {
+                println!("No matching records");
+            } else {
+                print_records(&records);
             }
-            
-            // Step 3: Generate HTML
-            info!("Step 3: Generating HTML report");
-            let generator = html::HtmlGenerator::new(&db);
-            if let Err(e) = generator.generate().await {
-                error!("HTML generation failed: {}", e);
+        }
+        Some(Commands::Attempts { signature }) => {
+            let db = Database::new(&config).await?;
+            let attempts = db.get_attempts_for(&signature).await?;
+            if attempts.is_empty() {
+                println!("No mint attempts recorded for {}", signature);
+            }
+            for attempt in attempts {
+                let detail = match (&attempt.tx_signature, &attempt.error) {
+                    (_, Some(error)) => error.as_str(),
+                    (Some(tx_signature), None) => tx_signature.as_str(),
+                    (None, None) => "",
+                };
+                println!(
+                    "{}  {:<8} {}",
+                    attempt.attempted_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                    attempt.result,
+                    detail
+                );
             }
         }
-        None => {
+        Some(Commands::Unmint { signature, yes }) => {
+            if !yes {
+                anyhow::bail!(
+                    "Unmint marks {} as pending so the next mint run mints it again; rerun with --yes to confirm",
+                    signature
+                );
+            }
+            let db = Database::new(&config).await?;
+            if !db.reset_mint(&signature).await? {
+                anyhow::bail!("No minted burn with signature {}", signature);
+            }
+            info!("↩️  Reset mint status of {}; the next mint run will reprocess it", signature);
+        }
+        Some(Commands::Healthcheck) => {
+            let checks = health::run_checks(&config).await;
+            health::report(&checks)?;
+        }
+        Some(Commands::Config { command: ConfigCommand::Check }) => {
+            let checks = health::run_config_checks(&config).await;
+            health::report(&checks)?;
+        }
+        Some(Commands::Verify) => {
+            info!("Verifying on-chain balances");
+            let db = Database::new(&config).await?;
+            let verifier = verifier::BalanceVerifier::new(&config, &db)?;
+            let summary = verifier.verify().await?;
+            println!(
+                "Verified {} wallets: {} matched, {} mismatched, {} could not be read",
+                summary.matched + summary.mismatched + summary.unverified,
+                summary.matched,
+                summary.mismatched,
+                summary.unverified
+            );
+        }
+        Some(Commands::Run { burner, .. }) => {
             info!("Running full pipeline");
+            let _lock = InstanceLock::acquire(&config)?;
+            run_pipeline(&config, burner.as_deref()).await?;
+        }
+        Some(Commands::Watch { interval_secs, burner, .. }) => {
+            info!("Watching for new burns every {} seconds", interval_secs);
+            let _lock = InstanceLock::acquire(&config)?;
+            let mut shutdown = ShutdownSignal::install()?;
+            let interval = Duration::from_secs(interval_secs);
+            let mut cycle: u64 = 0;
             
-            // Step 1: Migrate data (no specific burner)
-            info!("Step 1: Migrating data from burns.db");
-            let migrator = DatabaseMigrator::new(config.clone());
-            match migrator.migrate(None).await {
-                Ok(count) => {
-                    info!("Migrated {} records", count);
+            loop {
+                cycle += 1;
+                info!("🔄 Watch cycle {} started", cycle);
+                match run_pipeline(&config, burner.as_deref()).await {
+                    Ok((migrated, summary)) => {
+                        info!("🔄 Watch cycle {} finished: {} migrated, {} minted", cycle, migrated, summary.succeeded);
+                    }
+                    Err(e) => {
+                        error!("Watch cycle {} failed: {}", cycle, e);
+                    }
                 }
-                Err(e) => {
-                    error!("Migration failed: {}", e);
-                    return Err(e);
+                
+                tokio::select! {
+                    _ = shutdown.recv() => {
+                        info!("Shutdown signal received, stopping watch after {} cycles", cycle);
+                        break;
+                    }
+                    _ = tokio::time::sleep(interval) => {}
                 }
             }
-            
-            let db = Database::new(&config.database_url).await?;
-            
-            // Step 2: Process minting
-            info!("Step 2: Processing minting operations");
-            let mut minter = minter::TokenMinter::new(&config, &db).await?;
-            if let Err(e) = minter.process_pending_mints().await {
-                error!("Minting failed: {}", e);
-            }
-            
-            // Step 3: Generate HTML
-            info!("Step 3: Generating HTML report");
-            let generator = html::HtmlGenerator::new(&db);
-            if let Err(e) = generator.generate().await {
-                error!("HTML generation failed: {}", e);
-            }
+        }
+        Some(Commands::Serve { listen, cors_origins }) => {
+            let db = Database::new(&config).await?;
+            let mut shutdown = ShutdownSignal::install()?;
+            api::serve(listen, db, &cors_origins, async move { shutdown.recv().await }).await?;
+        }
+        None => {
+            info!("Running full pipeline");
+            let _lock = InstanceLock::acquire(&config)?;
+            run_pipeline(&config, None).await?;
         }
     }
     
-    info!("Process completed successfully");
     Ok(())
 }
+
+/// Migrate -> mint -> generate. Returns the number of records migrated and
+/// the outcome of the mint step.
+async fn run_pipeline(config: &Config, burner: Option<&str>) -> Result<(usize, MintSummary)> {
+    // Step 1: Migrate data
+    info!("Step 1: Migrating data from burns.db");
+    let migrator = DatabaseMigrator::new(config.clone());
+    let migrated = match migrator.migrate(burner).await {
+        Ok(report) => {
+            info!("Migrated {} records", report.migrated);
+            report.migrated
+        }
+        Err(e) => {
+            error!("Migration failed: {}", e);
+            return Err(e);
+        }
+    };
+    
+    let db = Database::new(config).await?;
+    
+    // Step 2: Process minting
+    info!("Step 2: Processing minting operations");
+    let mut minter = minter::TokenMinter::new(config, &db).await?;
+    let (summary, mint_error) = match minter.process_pending_mints(None).await {
+        Ok(summary) => {
+            summary.log();
+            (summary, None)
+        }
+        Err(e) => {
+            error!("Minting failed: {}", e);
+            (MintSummary::default(), Some(e))
+        }
+    };
+    
+    // Step 3: Generate HTML
+    info!("Step 3: Generating HTML report");
+    let generator = html::HtmlGenerator::new(config, &db);
+    if let Err(e) = generator.generate().await {
+        error!("HTML generation failed: {}", e);
+    }
+    
+    // Reported only now so a failed mint step still leaves an up-to-date report
+    if config.fail_on_mint_error {
+        if let Some(e) = mint_error {
+            return Err(e);
+        }
+        check_mint_failures(config, &summary)?;
+    }
+    
+    Ok((migrated, summary))
+}
+
+/// With --fail-on-mint-error, turn failed mints into an error so the process
+/// exits non-zero
+fn check_mint_failures(config: &Config, summary: &MintSummary) -> Result<()> {
+    if config.fail_on_mint_error && summary.failed > 0 {
+        anyhow::bail!("{} of {} attempted mints failed", summary.failed, summary.attempted);
+    }
+    Ok(())
+}
+
+fn print_statistics(stats: &Statistics) {
+    let rows = [
+        ("Total records", stats.total_records.to_string()),
+        ("Total burned", format!("{} solXEN", stats.total_burned_amount)),
+        ("Total minted", format!("{} solXEN", stats.total_minted_amount)),
+        ("Unique wallets", stats.unique_wallets.to_string()),
+        ("Pending mints", stats.pending_mints.to_string()),
+        ("Successful mints", stats.successful_mints.to_string()),
+        ("Failed mints", stats.failed_mints.to_string()),
+        (
+            "Success rate",
+            stats.success_rate.map_or("N/A".to_string(), |rate| format!("{:.1}%", rate * 100.0)),
+        ),
+        ("Fees paid", format!("{} lamports", stats.total_fees_lamports)),
+        ("Memos verified", stats.memo_checks.verified.to_string()),
+        ("Memos rejected", stats.memo_checks.rejected.to_string()),
+        ("Memos unchecked", stats.memo_checks.unchecked.to_string()),
+    ];
+    
+    println!("{:<18} {:>24}", "Statistic", "Value");
+    println!("{:-<18} {:->24}", "", "");
+    for (label, value) in rows {
+        println!("{:<18} {:>24}", label, value);
+    }
+}
+
+fn print_records(records: &[BurnRecord]) {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED).set_header(["Time (UTC)", "Burner", "Amount (solXEN)", "Status", "Mint signature"]);
+    for record in records {
+        let time = record.timestamp.unwrap_or(record.created_at);
+        table.add_row([
+            Cell::new(time.format("%Y-%m-%d %H:%M:%S")),
+            Cell::new(shorten_address(&record.burner)),
+            Cell::new(record.amount_as_decimal()).set_alignment(CellAlignment::Right),
+            Cell::new(if record.is_minted { "minted" } else { "pending" }),
+            Cell::new(record.minted_signature.as_deref().unwrap_or("-")),
+        ]);
+    }
+    println!("{}", table);
+}
+
+/// `AbCd…WxYz`, enough of an address to tell wallets apart in a table
+fn shorten_address(address: &str) -> String {
+    match (address.get(..4), address.get(address.len().saturating_sub(4)..)) {
+        (Some(head), Some(tail)) if address.len() > 10 => format!("{}…{}", head, tail),
+        _ => address.to_string(),
+    }
+}
+
+fn print_migration_report(report: &MigrationReport) {
+    let rows = [
+        ("Migrated", report.migrated),
+        ("Already migrated", report.skipped_existing),
+        ("Below minimum", report.below_minimum),
+        ("Above maximum", report.above_maximum),
+        ("Filtered by memo", report.filtered_by_memo),
+        ("Filtered by token", report.filtered_by_token),
+        ("Invalid signature", report.rejected_invalid),
+    ];
+    
+    println!("{:<18} {:>10}", "Source rows", "Count");
+    println!("{:-<18} {:->10}", "", "");
+    for (label, count) in rows {
+        println!("{:<18} {:>10}", label, count);
+    }
+}
+
+/// SIGINT/SIGTERM listener. Handlers are installed up front so a signal that
+/// arrives mid-cycle is held until the cycle finishes instead of killing the process.
+struct ShutdownSignal {
+    interrupt: Signal,
+    terminate: Signal,
+}
+
+impl ShutdownSignal {
+    fn install() -> Result<Self> {
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+        })
+    }
+    
+    async fn recv(&mut self) {
+        tokio::select! {
+            _ = self.interrupt.recv() => {}
+            _ = self.terminate.recv() => {}
+        }
+    }
+}
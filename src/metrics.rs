@@ -0,0 +1,102 @@
+use anyhow::Result;
+use axum::{routing::get, Router};
+use log::{error, info};
+use prometheus::{Encoder, Gauge, IntCounter, IntGauge, Registry, TextEncoder};
+use rust_decimal::prelude::ToPrimitive;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use crate::types::Statistics;
+
+/// Process-wide Prometheus metrics, updated by the minter and database
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+pub struct Metrics {
+    registry: Registry,
+    pub mints_total: IntCounter,
+    pub mint_failures_total: IntCounter,
+    pub pending_mints: IntGauge,
+    pub total_burned: Gauge,
+    pub total_minted: Gauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let mints_total = IntCounter::new("solxen_mints_total", "Successful mint transactions")
+            .expect("valid metric");
+        let mint_failures_total = IntCounter::new("solxen_mint_failures_total", "Failed mint attempts")
+            .expect("valid metric");
+        let pending_mints = IntGauge::new("solxen_pending_mints", "Burn records waiting to be minted")
+            .expect("valid metric");
+        let total_burned = Gauge::new("solxen_total_burned", "Total burned amount in solXEN")
+            .expect("valid metric");
+        let total_minted = Gauge::new("solxen_total_minted", "Total minted amount in solXEN")
+            .expect("valid metric");
+
+        registry.register(Box::new(mints_total.clone())).expect("unique metric");
+        registry.register(Box::new(mint_failures_total.clone())).expect("unique metric");
+        registry.register(Box::new(pending_mints.clone())).expect("unique metric");
+        registry.register(Box::new(total_burned.clone())).expect("unique metric");
+        registry.register(Box::new(total_minted.clone())).expect("unique metric");
+
+        Self {
+            registry,
+            mints_total,
+            mint_failures_total,
+            pending_mints,
+            total_burned,
+            total_minted,
+        }
+    }
+
+    /// Refresh the gauges from a freshly computed statistics snapshot
+    pub fn observe_statistics(&self, stats: &Statistics) {
+        self.pending_mints.set(stats.pending_mints);
+        self.total_burned.set(stats.total_burned_amount.to_f64().unwrap_or(0.0));
+        self.total_minted.set(stats.total_minted_amount.to_f64().unwrap_or(0.0));
+    }
+
+    fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// HTTP server exposing `/metrics` for the lifetime of the pipeline
+pub struct MetricsServer {
+    shutdown: oneshot::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl MetricsServer {
+    pub async fn start(addr: SocketAddr) -> Result<Self> {
+        let app = Router::new().route("/metrics", get(|| async { METRICS.render() }));
+        let server = axum::Server::try_bind(&addr)?.serve(app.into_make_service());
+        info!("Serving Prometheus metrics on http://{}/metrics", server.local_addr());
+
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            let graceful = server.with_graceful_shutdown(async {
+                shutdown_rx.await.ok();
+            });
+            if let Err(e) = graceful.await {
+                error!("Metrics server error: {}", e);
+            }
+        });
+
+        Ok(Self { shutdown, handle })
+    }
+
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.handle.await;
+        info!("Metrics server stopped");
+    }
+}
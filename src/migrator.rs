@@ -1,12 +1,49 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc, NaiveDateTime};
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use log::{info, warn};
-use rusqlite::{params, Connection, Row, OptionalExtension}; // 添加 OptionalExtension
+use rusqlite::{params, Connection, OpenFlags, Row};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+use solana_sdk::signature::Signature;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
-use crate::config::Config;
+use crate::config::{Config, DedupeRule};
+use crate::database::Database;
+use crate::progress::Progress;
+use crate::types::{MemoCheck, NewBurnRecord};
+
+/// Source `created_at` as unix seconds; the column holds either epoch numbers
+/// or date text depending on the export. Unparseable text yields NULL.
+const SOURCE_CREATED_AT_EPOCH: &str = "CASE WHEN typeof(created_at) IN ('integer', 'real') \
+     THEN CAST(created_at AS INTEGER) ELSE CAST(strftime('%s', created_at) AS INTEGER) END";
+
+/// Text timestamps that carry their own UTC offset
+const OFFSET_DATETIME_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S%.f%:z", "%Y-%m-%d %H:%M:%S%.f%z"];
+
+/// Text timestamps without an offset, read in `source_timezone`
+const NAIVE_DATETIME_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"];
+
+/// Columns a CSV export must have; the other source columns are optional
+const CSV_REQUIRED_COLUMNS: &[&str] = &["signature", "burner", "amount"];
+
+/// Columns every scan of a burns.db table selects
+const SOURCE_COLUMNS: &[&str] = &[
+    "signature", "burner", "amount", "memo", "token", "timestamp", "memo_checked", "created_at",
+];
+
+/// Times a source read that hits a busy or locked database is retried
+const SOURCE_BUSY_RETRIES: u32 = 5;
+
+/// Pause before retrying a busy source read
+const SOURCE_BUSY_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Rows `doctor` shows as they will be migrated
+const DOCTOR_SAMPLE_ROWS: usize = 5;
 
 pub struct DatabaseMigrator {
     config: Config,
@@ -17,74 +54,127 @@ impl DatabaseMigrator {
         Self { config }
     }
     
-    pub async fn migrate(&self, specific_burner: Option<&str>) -> Result<usize> {
-        if !self.config.source_db_path.exists() {
-            return Err(anyhow::anyhow!("Source database not found: {:?}", self.config.source_db_path));
+    /// Migrate every configured source into the destination. Signatures already
+    /// present are skipped, so a burn found in several shards is only copied once.
+    /// With several sources a missing file is skipped with a warning; it is an
+    /// error only when no source exists at all.
+    pub async fn migrate(&self, specific_burner: Option<&str>) -> Result<MigrationReport> {
+        let sources = &self.config.source_db_paths;
+        let available: Vec<&PathBuf> = sources.iter().filter(|path| path.exists()).collect();
+        
+        if available.is_empty() {
+            return Err(anyhow::anyhow!("Source database not found: {:?}", sources));
+        }
+        for missing in sources.iter().filter(|path| !path.exists()) {
+            warn!("Source database not found, skipping: {:?}", missing);
         }
         
         if let Some(burner) = specific_burner {
-            info!("Starting migration from {:?} for specific burner: {}", self.config.source_db_path, burner);
+            info!("Starting migration from {:?} for specific burner: {}", available, burner);
         } else {
-            info!("Starting migration from {:?}", self.config.source_db_path);
+            info!("Starting migration from {:?}", available);
         }
-        info!("Minimum burn amount: {} solXEN", self.config.min_burn_amount as f64 / 1_000_000.0);
+        info!("Minimum burn amount: {} solXEN", self.config.min_burn_amount_solxen());
         
-        // Open source database
-        let source_conn = Connection::open(&self.config.source_db_path)?;
+        let table = source_table_name(&self.config.source_table)?;
         
-        // Create destination database
-        let dest_path = self.config.database_url.strip_prefix("sqlite:").unwrap_or(&self.config.database_url);
-        if let Some(parent) = std::path::Path::new(dest_path).parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        let dest_conn = Connection::open(dest_path)?;
+        // Open the destination database and bring its schema up to date
+        let db = Database::new(&self.config).await?;
         
-        // Create destination table
-        self.create_destination_table(&dest_conn)?;
+        if self.config.dedupe_sources && specific_burner.is_none() {
+            let report = self.migrate_deduplicated(&available, table, &db).await?;
+            info!("Migration completed: {} records migrated", report.migrated);
+            return Ok(report);
+        }
         
-        // Migrate data
-        let migrated_count = self.migrate_data(&source_conn, &dest_conn, specific_burner).await?;
+        let mut report = MigrationReport::default();
+        let mut per_source = Vec::new();
+        for source in available {
+            info!("Migrating from {:?}", source);
+            let source_conn = Connection::open(source)?;
+            ensure_table_exists(&source_conn, source, table)?;
+            let source_key = checkpoint_key(source, table);
+            let source_report = self.migrate_data(&source_conn, table, &source_key, &db, specific_burner).await?;
+            report.add(&source_report);
+            per_source.push((source, source_report.migrated));
+        }
         
-        info!("Migration completed: {} records migrated", migrated_count);
-        Ok(migrated_count)
+        if per_source.len() > 1 {
+            for (source, count) in &per_source {
+                info!("   {:?}: {} records migrated", source, count);
+            }
+        }
+        info!("Migration completed: {} records migrated", report.migrated);
+        Ok(report)
     }
     
-    fn create_destination_table(&self, conn: &Connection) -> Result<()> {
-        conn.execute(
-            r#"
-            CREATE TABLE IF NOT EXISTS burn_records (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                signature TEXT UNIQUE NOT NULL,
-                burner TEXT NOT NULL,
-                amount DECIMAL(20,6) NOT NULL,
-                memo TEXT,
-                token TEXT,
-                timestamp DATETIME,
-                memo_checked CHAR(1),
-                created_at DATETIME NOT NULL,
-                is_minted BOOLEAN DEFAULT FALSE NOT NULL,
-                minted_time DATETIME,
-                minted_signature TEXT
-            )
-            "#,
-            [],
-        )?;
-        
-        // Create indexes
-        conn.execute("CREATE INDEX IF NOT EXISTS idx_signature ON burn_records(signature)", [])?;
-        conn.execute("CREATE INDEX IF NOT EXISTS idx_burner ON burn_records(burner)", [])?;
-        conn.execute("CREATE INDEX IF NOT EXISTS idx_amount ON burn_records(amount)", [])?;
-        conn.execute("CREATE INDEX IF NOT EXISTS idx_is_minted ON burn_records(is_minted)", [])?;
-        conn.execute("CREATE INDEX IF NOT EXISTS idx_timestamp ON burn_records(timestamp)", [])?;
-        
-        info!("Destination table created");
-        Ok(())
+    /// Migrate burns from a CSV export whose header names the source columns.
+    /// Rows go through the same dedupe and filters as burns.db rows; there is
+    /// no checkpoint, already migrated signatures are simply skipped.
+    pub async fn migrate_csv(&self, path: &Path) -> Result<MigrationReport> {
+        info!("Starting migration from CSV {:?}", path);
+        info!("Minimum burn amount: {} solXEN", self.config.min_burn_amount_solxen());
+        
+        let mut reader = csv::Reader::from_path(path)?;
+        let headers = reader.headers()?.clone();
+        let column = |name: &str| headers.iter().position(|header| header.trim().eq_ignore_ascii_case(name));
+        
+        let missing: Vec<&str> = CSV_REQUIRED_COLUMNS.iter().copied().filter(|name| column(name).is_none()).collect();
+        if !missing.is_empty() {
+            return Err(anyhow::anyhow!(
+                "CSV {:?} is missing required columns: {} (found: {})",
+                path,
+                missing.join(", "),
+                headers.iter().collect::<Vec<_>>().join(", ")
+            ));
+        }
+        
+        let columns = CsvColumns {
+            signature: column("signature").unwrap_or_default(),
+            burner: column("burner").unwrap_or_default(),
+            amount: column("amount").unwrap_or_default(),
+            memo: column("memo"),
+            token: column("token"),
+            timestamp: column("timestamp"),
+            memo_checked: column("memo_checked"),
+            created_at: column("created_at"),
+        };
+        
+        let rows = reader.records().collect::<csv::Result<Vec<_>>>()?;
+        let db = Database::new(&self.config).await?;
+        
+        let mut report = MigrationReport::default();
+        let mut progress = Progress::new("Migrating", rows.len() as u64);
+        
+        for (index, row) in rows.iter().enumerate() {
+            // Line 1 is the header
+            let record = self.csv_row_to_burn_record(row, &columns)
+                .map_err(|e| anyhow::anyhow!("CSV {:?} line {}: {}", path, index + 2, e))?;
+            self.process_single_record(
+                record,
+                &db,
+                &mut report,
+            ).await?;
+            progress.inc();
+        }
+        progress.finish();
+        
+        info!(
+            "Migration completed: {} records migrated ({} existing, {} below minimum, {} above maximum, {} filtered by memo, {} by token, {} invalid signatures)",
+            report.migrated, report.skipped_existing, report.below_minimum, report.above_maximum, report.filtered_by_memo, report.filtered_by_token, report.rejected_invalid
+        );
+        Ok(report)
     }
     
-    async fn migrate_data(&self, source_conn: &Connection, dest_conn: &Connection, specific_burner: Option<&str>) -> Result<usize> {
-        let mut migrated_count = 0;
-        let mut skipped_count = 0;
-        let mut below_minimum_count = 0;
+    async fn migrate_data(
+        &self,
+        source_conn: &Connection,
+        table: &str,
+        source_key: &str,
+        db: &Database,
+        specific_burner: Option<&str>,
+    ) -> Result<MigrationReport> {
+        let mut report = MigrationReport::default();
         
         // Handle specific burner case
         if let Some(burner) = specific_burner {
@@ -92,7 +182,7 @@ impl DatabaseMigrator {
             
             // First, let's check if this burner exists at all
             let burner_count: i64 = source_conn.query_row(
-                "SELECT COUNT(*) FROM burns WHERE burner = ?1",
+                &format!("SELECT COUNT(*) FROM \"{}\" WHERE burner = ?1", table),
                 params![burner],
                 |row| row.get(0),
             )?;
@@ -101,17 +191,18 @@ impl DatabaseMigrator {
             
             if burner_count == 0 {
                 warn!("No records found for burner: {}", burner);
-                return Ok(0);
+                return Ok(report);
             }
             
             // Query all records for this burner, ordered by timestamp DESC
             // We'll process them one by one until we find one that meets the minimum amount
-            let mut stmt = source_conn.prepare(
+            let mut stmt = source_conn.prepare(&format!(
                 "SELECT signature, burner, amount, memo, token, timestamp, memo_checked, created_at 
-                 FROM burns 
+                 FROM \"{}\" 
                  WHERE burner = ?1 
-                 ORDER BY timestamp DESC"
-            )?;
+                 ORDER BY timestamp DESC",
+                table
+            ))?;
             
             let record_iter = stmt.query_map(params![burner], |row| {
                 self.row_to_burn_record(row)
@@ -122,6 +213,12 @@ impl DatabaseMigrator {
             for record_result in record_iter {
                 let record = record_result?;
                 
+                if !is_valid_signature(&record.signature) {
+                    report.rejected_invalid += 1;
+                    warn!("Rejecting burn with malformed signature {:?}, checking next record", record.signature);
+                    continue;
+                }
+                
                 info!(
                     "Checking record: burner={}, amount={}, signature={}", 
                     record.burner,
@@ -130,28 +227,41 @@ impl DatabaseMigrator {
                 );
                 
                 // Check if this record already exists in destination
-                let exists: i64 = dest_conn.query_row(
-                    "SELECT COUNT(*) FROM burn_records WHERE signature = ?1",
-                    params![record.signature],
-                    |row| row.get(0),
-                )?;
-                
-                if exists > 0 {
-                    skipped_count += 1;
+                if db.signature_exists(&record.signature).await? {
+                    report.skipped_existing += 1;
                     info!("Record {} already exists, checking next record", record.signature);
                     continue;
                 }
                 
+                if !self.config.memo_allowed(record.memo.as_deref()) {
+                    report.filtered_by_memo += 1;
+                    info!("Memo {:?} filtered out, checking next record", record.memo);
+                    continue;
+                }
+                
+                if !self.config.token_allowed(record.token.as_deref()) {
+                    report.filtered_by_token += 1;
+                    info!("Token {:?} filtered out, checking next record", record.token);
+                    continue;
+                }
+                
                 // Check if amount meets minimum requirement
                 let raw_amount = record.amount.to_u64().unwrap_or(0);
                 
                 info!("Raw amount: {}, Min required: {}", raw_amount, self.config.min_burn_amount);
                 
                 if raw_amount < self.config.min_burn_amount {
+                    report.below_minimum += 1;
                     info!("Amount {} below minimum, checking next record", raw_amount);
                     continue; // Keep looking for a record that meets the requirement
                 }
                 
+                if self.config.exceeds_max_burn(raw_amount) {
+                    report.above_maximum += 1;
+                    warn!("Amount {} above max_burn_amount, checking next record", raw_amount);
+                    continue;
+                }
+                
                 // Found a valid record, process it
                 info!(
                     "Found valid record: burner={}, amount={} ({}), signature={}", 
@@ -162,81 +272,443 @@ impl DatabaseMigrator {
                 );
                 
                 // Insert the record
-                dest_conn.execute(
-                    r#"
-                    INSERT INTO burn_records (
-                        signature, burner, amount, memo, token, timestamp, memo_checked, 
-                        created_at, is_minted, minted_time, minted_signature
-                    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
-                    "#,
-                    params![
-                        record.signature,
-                        record.burner,
-                        raw_amount as i64,
-                        record.memo,
-                        record.token,
-                        record.timestamp.map(|t| t.to_rfc3339()),
-                        record.memo_checked,
-                        record.created_at.to_rfc3339(),
-                        false, // is_minted default to false
-                        None::<String>, // minted_time
-                        None::<String>, // minted_signature
-                    ],
-                )?;
+                db.insert_burn_record(&record.into_new_record(raw_amount)).await?;
                 
-                migrated_count += 1;
+                report.migrated += 1;
                 found_valid_record = true;
                 info!("Successfully migrated 1 record for burner {}", burner);
                 break; // Only migrate one record per burner
             }
             
             if !found_valid_record {
-                warn!("No records found for burner {} that meet the minimum amount requirement (420 solXEN)", burner);
+                warn!("No records found for burner {} that meet the minimum amount requirement ({} solXEN)", burner, self.config.min_burn_amount_solxen());
             }
             
         } else {
-            info!("Migrating all burn records");
+            // Read the new checkpoint before scanning so rows added during the
+            // scan are picked up next time. Rows at the old checkpoint are
+            // scanned again; the signature check skips them.
+            let next_checkpoint = latest_created_at(source_conn, table)?;
+            let checkpoint = self.scan_checkpoint(db, source_key).await?;
             
-            // Query all records
-            let mut stmt = source_conn.prepare(
-                "SELECT signature, burner, amount, memo, token, timestamp, memo_checked, created_at 
-                 FROM burns 
-                 ORDER BY timestamp DESC"
+            match checkpoint {
+                Some(checkpoint) => info!(
+                    "Migrating burn records created since {} (use --full to rescan everything)",
+                    DateTime::from_timestamp(checkpoint, 0).unwrap_or_default()
+                ),
+                None => info!("Migrating all burn records"),
+            }
+            
+            let to_scan: u64 = source_conn.query_row(
+                &format!("SELECT COUNT(*) FROM \"{}\" WHERE {}", table, since_checkpoint()),
+                params![checkpoint],
+                |row| row.get(0),
             )?;
+            let mut progress = Progress::new("Migrating", to_scan);
+            
+            // Query all records newer than the checkpoint
+            let mut scanned = 0;
+            let scan = self.scan_source(source_conn, table, checkpoint, db, &mut report, &mut scanned, &mut progress).await;
+            progress.finish();
+            
+            if let Err(e) = scan {
+                // Every migrated row is already committed; move the checkpoint
+                // up to the oldest row not scanned yet so the rerun resumes there
+                warn!(
+                    "Migration from {} aborted after {} of {} rows ({} migrated, {} skipped)",
+                    source_key, scanned, to_scan, report.migrated, report.skipped()
+                );
+                match resume_checkpoint(source_conn, table, checkpoint, scanned) {
+                    Ok(resume) => {
+                        let resume = match (resume, next_checkpoint) {
+                            (Some(resume), Some(next)) => Some(resume.min(next)),
+                            (resume, next) => resume.or(next),
+                        };
+                        if let Some(resume) = resume.filter(|resume| checkpoint.is_none_or(|checkpoint| *resume > checkpoint)) {
+                            db.set_migration_checkpoint(source_key, resume).await?;
+                            info!(
+                                "Checkpoint moved to {}; rerun migrate to continue",
+                                DateTime::from_timestamp(resume, 0).unwrap_or_default()
+                            );
+                        }
+                    }
+                    Err(checkpoint_error) => warn!("Could not save a resume checkpoint: {}", checkpoint_error),
+                }
+                return Err(e);
+            }
             
-            let record_iter = stmt.query_map([], |row| {
+            if let Some(next_checkpoint) = next_checkpoint {
+                db.set_migration_checkpoint(source_key, next_checkpoint).await?;
+            }
+        }
+        
+        if report.skipped_existing > 0 {
+            info!("Skipped {} existing records", report.skipped_existing);
+        }
+        
+        if report.below_minimum > 0 {
+            info!("Skipped {} records below minimum burn amount ({} solXEN)", report.below_minimum, self.config.min_burn_amount_solxen());
+        }
+        
+        if report.above_maximum > 0 {
+            warn!(
+                "Rejected {} records above max_burn_amount ({} raw units); pass --force to migrate them",
+                report.above_maximum,
+                self.config.max_burn_amount.unwrap_or_default()
+            );
+        }
+        
+        if report.filtered_by_memo > 0 {
+            info!("Skipped {} records filtered out by memo", report.filtered_by_memo);
+        }
+        
+        if report.filtered_by_token > 0 {
+            info!(
+                "Skipped {} records for other tokens (only {} is migrated)",
+                report.filtered_by_token,
+                self.config.source_token_filter.as_deref().unwrap_or_default()
+            );
+        }
+        
+        if report.rejected_invalid > 0 {
+            warn!("Rejected {} records with malformed signatures", report.rejected_invalid);
+        }
+        
+        if let Some(burner) = specific_burner {
+            if report.migrated == 0 && report.skipped() == 0 {
+                warn!("No qualifying records found for burner: {}", burner);
+            }
+        }
+        
+        Ok(report)
+    }
+    
+    /// Migrate the rows at or after `checkpoint`, counting them in `scanned`.
+    /// A read that finds the source busy or locked (e.g. the burn indexer is
+    /// writing) is retried after a short pause, reopening the scan past the
+    /// rows already done.
+    #[allow(clippy::too_many_arguments)]
+    async fn scan_source(
+        &self,
+        source_conn: &Connection,
+        table: &str,
+        checkpoint: Option<i64>,
+        db: &Database,
+        report: &mut MigrationReport,
+        scanned: &mut u64,
+        progress: &mut Progress,
+    ) -> Result<()> {
+        let mut retries = 0;
+        'scan: loop {
+            let mut stmt = source_conn.prepare(&scan_query(table))?;
+            let record_iter = stmt.query_map(params![checkpoint, *scanned as i64], |row| {
                 self.row_to_burn_record(row)
             })?;
             
             for record_result in record_iter {
-                let record = record_result?;
-                migrated_count += self.process_single_record(record, dest_conn, &mut skipped_count, &mut below_minimum_count).await?;
+                let record = match record_result {
+                    Ok(record) => record,
+                    Err(e) if is_busy(&e) && retries < SOURCE_BUSY_RETRIES => {
+                        retries += 1;
+                        warn!(
+                            "Source database busy after {} rows, retrying ({}/{}): {}",
+                            scanned, retries, SOURCE_BUSY_RETRIES, e
+                        );
+                        tokio::time::sleep(SOURCE_BUSY_RETRY_DELAY).await;
+                        continue 'scan;
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+                retries = 0;
+                self.process_single_record(
+                    record,
+                    db,
+                    report,
+                ).await?;
+                *scanned += 1;
+                progress.inc();
             }
+            return Ok(());
         }
+    }
+    
+    /// `--dedupe`: read the new rows of every source, keep one row per
+    /// signature according to `dedupe_rule`, then migrate the survivors
+    async fn migrate_deduplicated(&self, sources: &[&PathBuf], table: &str, db: &Database) -> Result<MigrationReport> {
+        let mut candidates: HashMap<String, BurnRecordSource> = HashMap::new();
+        let mut duplicate_count = 0;
+        let mut checkpoints = Vec::new();
         
-        if skipped_count > 0 {
-            info!("Skipped {} existing records", skipped_count);
+        for source in sources {
+            info!("Reading {:?}", source);
+            let source_conn = Connection::open(source)?;
+            ensure_table_exists(&source_conn, source, table)?;
+            let source_key = checkpoint_key(source, table);
+            let next_checkpoint = latest_created_at(&source_conn, table)?;
+            let checkpoint = self.scan_checkpoint(db, &source_key).await?;
+            
+            let mut stmt = source_conn.prepare(&scan_query(table))?;
+            let rows = stmt.query_map(params![checkpoint, 0], |row| self.row_to_burn_record(row))?;
+            for row in rows {
+                let record = row?;
+                match candidates.entry(record.signature.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(record);
+                    }
+                    Entry::Occupied(mut entry) => {
+                        duplicate_count += 1;
+                        if record.preferred_over(entry.get(), self.config.dedupe_rule) {
+                            entry.insert(record);
+                        }
+                    }
+                }
+            }
+            checkpoints.push((source_key, next_checkpoint));
         }
         
-        if below_minimum_count > 0 {
-            info!("Skipped {} records below minimum burn amount (420 solXEN)", below_minimum_count);
+        info!(
+            "Collapsed {} duplicate source rows, {} unique signatures remain (keeping {:?})",
+            duplicate_count,
+            candidates.len(),
+            self.config.dedupe_rule
+        );
+        
+        // Same order as a regular scan: newest burns first
+        let mut records: Vec<BurnRecordSource> = candidates.into_values().collect();
+        records.sort_by_key(|record| std::cmp::Reverse(record.timestamp));
+        
+        let mut report = MigrationReport::default();
+        let mut progress = Progress::new("Migrating", records.len() as u64);
+        
+        for record in records {
+            self.process_single_record(
+                record,
+                db,
+                &mut report,
+            ).await?;
+            progress.inc();
         }
+        progress.finish();
         
-        if specific_burner.is_some() && migrated_count == 0 && skipped_count == 0 && below_minimum_count == 0 {
-            warn!("No qualifying records found for burner: {}", specific_burner.unwrap());
+        for (source_key, next_checkpoint) in checkpoints {
+            if let Some(next_checkpoint) = next_checkpoint {
+                db.set_migration_checkpoint(&source_key, next_checkpoint).await?;
+            }
+        }
+        
+        info!(
+            "Skipped {} existing, {} below minimum, {} above maximum, {} filtered by memo, {} by token, {} invalid signatures",
+            report.skipped_existing, report.below_minimum, report.above_maximum, report.filtered_by_memo, report.filtered_by_token, report.rejected_invalid
+        );
+        Ok(report)
+    }
+    
+    /// Print how each source's burns table will be read, without writing
+    /// anything: declared and stored column types, how amounts and timestamps
+    /// are interpreted and a few sample rows. Fails when a source can't be
+    /// migrated at all.
+    pub fn doctor(&self) -> Result<()> {
+        let table = source_table_name(&self.config.source_table)?;
+        let sources = &self.config.source_db_paths;
+        let mut broken = 0;
+        
+        for source in sources {
+            println!("📂 {} (table {})", source.display(), table);
+            if let Err(e) = self.inspect_source(source, table) {
+                broken += 1;
+                println!("   ❌ {}", e);
+            }
+            println!();
+        }
+        
+        if broken > 0 {
+            bail!("{} of {} sources cannot be migrated", broken, sources.len());
+        }
+        Ok(())
+    }
+    
+    fn inspect_source(&self, source: &Path, table: &str) -> Result<()> {
+        if !source.exists() {
+            bail!("Source database not found");
+        }
+        let conn = Connection::open_with_flags(source, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        ensure_table_exists(&conn, source, table)?;
+        
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info(\"{}\")", table))?;
+        let declared: HashMap<String, String> = stmt
+            .query_map([], |row| Ok((row.get::<_, String>("name")?.to_lowercase(), row.get::<_, String>("type")?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        let row_count: i64 = conn.query_row(&format!("SELECT COUNT(*) FROM \"{}\"", table), [], |row| row.get(0))?;
+        println!("   {} rows", row_count);
+        
+        println!("   {:<14} {:<10} Stored values", "Column", "Declared");
+        for column in SOURCE_COLUMNS {
+            match declared.get(*column) {
+                Some(declared_type) => {
+                    let stored = stored_types(&conn, table, column)?
+                        .iter()
+                        .map(|(kind, count)| format!("{} {}", count, kind))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let declared_type = if declared_type.is_empty() { "(none)" } else { declared_type.as_str() };
+                    println!("   {:<14} {:<10} {}", column, declared_type, stored);
+                }
+                None => println!("   {:<14} {:<10} missing", column, "-"),
+            }
+        }
+        
+        let missing: Vec<&str> = SOURCE_COLUMNS.iter().copied().filter(|column| !declared.contains_key(*column)).collect();
+        if !missing.is_empty() {
+            bail!("Missing columns {}; every scan selects them", missing.join(", "));
+        }
+        
+        println!();
+        self.explain_amounts(&conn, table)?;
+        self.explain_timestamps(&conn, table)?;
+        
+        println!();
+        println!("   Sample rows as they will be migrated:");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT signature, burner, amount, memo, token, timestamp, memo_checked, created_at,
+                    quote(amount) AS amount_source, quote(timestamp) AS timestamp_source
+             FROM \"{}\" ORDER BY timestamp DESC LIMIT ?1",
+            table
+        ))?;
+        let samples = stmt.query_map(params![DOCTOR_SAMPLE_ROWS as i64], |row| {
+            Ok((
+                self.row_to_burn_record(row)?,
+                row.get::<_, String>("amount_source")?,
+                row.get::<_, String>("timestamp_source")?,
+            ))
+        })?;
+        for sample in samples {
+            let (record, amount_source, timestamp_source) = sample?;
+            let raw_amount = record.amount.to_u64().unwrap_or(0);
+            println!(
+                "   {}  amount {} -> {} solXEN  timestamp {} -> {}",
+                &record.signature[..record.signature.char_indices().nth(8).map_or(record.signature.len(), |(i, _)| i)],
+                amount_source,
+                (Decimal::from(raw_amount) / Decimal::from(1_000_000)).normalize(),
+                timestamp_source,
+                record.timestamp.map_or("(none, created_at is shown)".to_string(), |t| t.to_rfc3339()),
+            );
+        }
+        Ok(())
+    }
+    
+    /// How amounts will be read: as raw units (6 decimals), whatever their type
+    fn explain_amounts(&self, conn: &Connection, table: &str) -> Result<()> {
+        let count = |condition: &str| -> rusqlite::Result<i64> {
+            conn.query_row(
+                &format!("SELECT COUNT(*) FROM \"{}\" WHERE {}", table, condition),
+                [],
+                |row| row.get(0),
+            )
+        };
+        
+        println!("   amount: read as raw units with 6 decimals (420690000 = 420.69 solXEN)");
+        let real = count("typeof(amount) = 'real'")?;
+        if real > 0 {
+            println!("   ⚠️  {} amounts are floats; large values may have lost precision", real);
+        }
+        let unparseable: i64 = {
+            let mut stmt = conn.prepare(&format!("SELECT amount FROM \"{}\" WHERE typeof(amount) = 'text'", table))?;
+            let texts = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            let mut unparseable = 0;
+            for text in texts {
+                if Decimal::from_str(&text?).is_err() {
+                    unparseable += 1;
+                }
+            }
+            unparseable
+        };
+        let null = count("amount IS NULL")?;
+        if unparseable + null > 0 {
+            println!("   ⚠️  {} amounts are empty or not numbers and will be read as 0", unparseable + null);
+        }
+        let fractional = count("CAST(amount AS REAL) != CAST(CAST(amount AS REAL) AS INTEGER)")?;
+        if fractional > 0 {
+            println!(
+                "   ⚠️  {} amounts have a fractional part; raw units are whole numbers, so these look like solXEN amounts",
+                fractional
+            );
         }
+        let below_one = count("CAST(amount AS REAL) > 0 AND CAST(amount AS REAL) < 1000000")?;
+        if below_one > 0 {
+            println!(
+                "   ⚠️  {} amounts are below 1 solXEN in raw units; if the source stores whole solXEN they will all fall below the minimum",
+                below_one
+            );
+        }
+        Ok(())
+    }
+    
+    /// How burn timestamps will be read: integers as unix seconds, text in the
+    /// formats `parse_datetime` accepts, anything else not at all
+    fn explain_timestamps(&self, conn: &Connection, table: &str) -> Result<()> {
+        let count = |condition: &str| -> rusqlite::Result<i64> {
+            conn.query_row(
+                &format!("SELECT COUNT(*) FROM \"{}\" WHERE {}", table, condition),
+                [],
+                |row| row.get(0),
+            )
+        };
         
-        Ok(migrated_count)
+        println!(
+            "   timestamp: integers are unix seconds; text is RFC 3339 or YYYY-MM-DD HH:MM:SS (in {} without an offset)",
+            self.config.source_timezone
+        );
+        let milliseconds = count("typeof(timestamp) = 'integer' AND timestamp > 100000000000")?;
+        if milliseconds > 0 {
+            println!("   ⚠️  {} integer timestamps look like milliseconds and will land far in the future", milliseconds);
+        }
+        let real = count("typeof(timestamp) = 'real'")?;
+        if real > 0 {
+            println!("   ⚠️  {} timestamps are floats, which are not read; they will be left empty", real);
+        }
+        let unparseable: i64 = {
+            let mut stmt = conn.prepare(&format!("SELECT timestamp FROM \"{}\" WHERE typeof(timestamp) = 'text'", table))?;
+            let texts = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            let mut unparseable = 0;
+            for text in texts {
+                if self.parse_datetime(&text?).is_err() {
+                    unparseable += 1;
+                }
+            }
+            unparseable
+        };
+        if unparseable > 0 {
+            println!("   ⚠️  {} text timestamps don't parse and will be left empty", unparseable);
+        }
+        let null = count("timestamp IS NULL")?;
+        if null > 0 {
+            println!("   {} rows have no timestamp; the report shows their created_at instead", null);
+        }
+        Ok(())
+    }
+    
+    /// Checkpoint to resume `source_key` from, unless running with --full
+    async fn scan_checkpoint(&self, db: &Database, source_key: &str) -> Result<Option<i64>> {
+        if self.config.incremental_migration {
+            db.get_migration_checkpoint(source_key).await
+        } else {
+            Ok(None)
+        }
     }
     
     // 新增辅助方法来处理单个记录
     async fn process_single_record(
         &self, 
         record: BurnRecordSource, 
-        dest_conn: &Connection, 
-        skipped_count: &mut usize, 
-        below_minimum_count: &mut usize
-    ) -> Result<usize> {
+        db: &Database, 
+        report: &mut MigrationReport,
+    ) -> Result<()> {
+        if !is_valid_signature(&record.signature) {
+            report.rejected_invalid += 1;
+            warn!("Rejecting burn with malformed signature {:?} (burner {})", record.signature, record.burner);
+            return Ok(());
+        }
+        
         info!(
             "Processing record: burner={}, amount={}, signature={}", 
             record.burner,
@@ -245,16 +717,22 @@ impl DatabaseMigrator {
         );
         
         // Check if record already exists
-        let exists: i64 = dest_conn.query_row(
-            "SELECT COUNT(*) FROM burn_records WHERE signature = ?1",
-            params![record.signature],
-            |row| row.get(0),
-        )?;
-        
-        if exists > 0 {
-            *skipped_count += 1;
+        if db.signature_exists(&record.signature).await? {
+            report.skipped_existing += 1;
             info!("Record {} already exists, skipping", record.signature);
-            return Ok(0);
+            return Ok(());
+        }
+        
+        if !self.config.memo_allowed(record.memo.as_deref()) {
+            report.filtered_by_memo += 1;
+            info!("Skipping burn {} filtered out by memo {:?}", record.signature, record.memo);
+            return Ok(());
+        }
+        
+        if !self.config.token_allowed(record.token.as_deref()) {
+            report.filtered_by_token += 1;
+            info!("Skipping burn {} of token {:?}", record.signature, record.token);
+            return Ok(());
         }
         
         // 直接使用原始amount值，不做任何转换
@@ -264,9 +742,15 @@ impl DatabaseMigrator {
         
         // 最小值检查：420 solXEN = 420000000 (按6位小数计算)
         if raw_amount < self.config.min_burn_amount {
-            *below_minimum_count += 1;
-            info!("Skipping burn with amount {} (below minimum of 420 solXEN)", record.amount);
-            return Ok(0);
+            report.below_minimum += 1;
+            info!("Skipping burn with amount {} (below minimum of {} solXEN)", record.amount, self.config.min_burn_amount_solxen());
+            return Ok(());
+        }
+        
+        if self.config.exceeds_max_burn(raw_amount) {
+            report.above_maximum += 1;
+            warn!("Rejecting burn {} of {} raw units, above max_burn_amount", record.signature, raw_amount);
+            return Ok(());
         }
         
         info!(
@@ -278,30 +762,11 @@ impl DatabaseMigrator {
         );
         
         // Insert the record with original amount (直接复制原始值)
-        dest_conn.execute(
-            r#"
-            INSERT INTO burn_records (
-                signature, burner, amount, memo, token, timestamp, memo_checked, 
-                created_at, is_minted, minted_time, minted_signature
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
-            "#,
-            params![
-                record.signature,
-                record.burner,
-                raw_amount as i64, // 直接存储：420690000 -> 420690000
-                record.memo,
-                record.token,
-                record.timestamp.map(|t| t.to_rfc3339()),
-                record.memo_checked,
-                record.created_at.to_rfc3339(),
-                false, // is_minted default to false
-                None::<String>, // minted_time
-                None::<String>, // minted_signature
-            ],
-        )?;
+        db.insert_burn_record(&record.into_new_record(raw_amount)).await?;
+        report.migrated += 1;
         
         info!("Successfully migrated 1 record");
-        Ok(1)
+        Ok(())
     }
     
     fn row_to_burn_record(&self, row: &Row) -> rusqlite::Result<BurnRecordSource> {
@@ -357,28 +822,238 @@ impl DatabaseMigrator {
         })
     }
     
+    fn csv_row_to_burn_record(&self, row: &csv::StringRecord, columns: &CsvColumns) -> Result<BurnRecordSource> {
+        let field = |index: Option<usize>| {
+            index
+                .and_then(|index| row.get(index))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+        
+        let signature = field(Some(columns.signature)).ok_or_else(|| anyhow::anyhow!("empty signature"))?;
+        let burner = field(Some(columns.burner)).ok_or_else(|| anyhow::anyhow!("empty burner"))?;
+        let amount = field(Some(columns.amount)).unwrap_or("0");
+        let amount = Decimal::from_str(amount)
+            .or_else(|_| Decimal::from_scientific(amount))
+            .map_err(|_| anyhow::anyhow!("invalid amount {:?}", amount))?;
+        
+        // Times are unix seconds or date text, as in burns.db
+        let parse_time = |value: &str| match value.parse::<i64>() {
+            Ok(seconds) => DateTime::from_timestamp(seconds, 0),
+            Err(_) => self.parse_datetime(value).ok(),
+        };
+        
+        Ok(BurnRecordSource {
+            signature: signature.to_string(),
+            burner: burner.to_string(),
+            amount,
+            memo: field(columns.memo).map(str::to_string),
+            token: field(columns.token).map(str::to_string),
+            timestamp: field(columns.timestamp).and_then(parse_time),
+            memo_checked: field(columns.memo_checked).map(str::to_string),
+            created_at: field(columns.created_at).and_then(parse_time).unwrap_or_else(Utc::now),
+        })
+    }
+    
+    /// Parse a source date: RFC 3339 or `YYYY-MM-DD HH:MM:SS` with an offset
+    /// as written, or without one in `source_timezone`. Either way the result
+    /// is UTC, which the destination stores as canonical RFC 3339.
     fn parse_datetime(&self, date_str: &str) -> Result<DateTime<Utc>> {
-        // Try different datetime formats
+        let date_str = date_str.trim();
         if let Ok(dt) = DateTime::parse_from_rfc3339(date_str) {
             return Ok(dt.with_timezone(&Utc));
         }
         
-        if let Ok(dt) = DateTime::parse_from_str(date_str, "%Y-%m-%d %H:%M:%S") {
-            return Ok(dt.with_timezone(&Utc));
-        }
-        
-        if let Ok(naive_dt) = NaiveDateTime::parse_from_str(date_str, "%Y-%m-%d %H:%M:%S") {
-            return Ok(DateTime::from_naive_utc_and_offset(naive_dt, Utc));
+        for format in OFFSET_DATETIME_FORMATS {
+            if let Ok(dt) = DateTime::parse_from_str(date_str, format) {
+                return Ok(dt.with_timezone(&Utc));
+            }
         }
         
-        if let Ok(naive_dt) = NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S") {
-            return Ok(DateTime::from_naive_utc_and_offset(naive_dt, Utc));
+        for format in NAIVE_DATETIME_FORMATS {
+            if let Ok(naive_dt) = NaiveDateTime::parse_from_str(date_str, format) {
+                // A time repeated by a DST change resolves to its first occurrence
+                return match self.config.source_timezone.from_local_datetime(&naive_dt).earliest() {
+                    Some(dt) => Ok(dt.with_timezone(&Utc)),
+                    None => Err(anyhow::anyhow!(
+                        "{} does not exist in {} (skipped by a DST change)",
+                        date_str,
+                        self.config.source_timezone
+                    )),
+                };
+            }
         }
         
         Err(anyhow::anyhow!("Unable to parse datetime: {}", date_str))
     }
 }
 
+/// The configured source table, checked to be a plain SQL identifier since
+/// it is interpolated into queries
+fn source_table_name(name: &str) -> Result<&str> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    
+    if !valid {
+        return Err(anyhow::anyhow!("Invalid source table name {:?}: use letters, digits and underscores only", name));
+    }
+    Ok(name)
+}
+
+/// Whether `signature` is a base58 transaction signature of the full 64 bytes;
+/// truncated or mangled source rows would otherwise become dead explorer links
+fn is_valid_signature(signature: &str) -> bool {
+    Signature::from_str(signature).is_ok()
+}
+
+/// Storage classes of `column`'s values with their counts, most common first
+fn stored_types(conn: &Connection, table: &str, column: &str) -> Result<Vec<(String, i64)>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT typeof(\"{column}\"), COUNT(*) FROM \"{table}\" GROUP BY 1 ORDER BY 2 DESC",
+        column = column,
+        table = table
+    ))?;
+    let types = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(types)
+}
+
+/// Key of a source's checkpoint: the canonical path plus the table name
+fn checkpoint_key(source: &Path, table: &str) -> String {
+    format!(
+        "{}#{}",
+        std::fs::canonicalize(source).unwrap_or_else(|_| source.to_path_buf()).display(),
+        table
+    )
+}
+
+/// Newest `created_at` in the source, as unix seconds
+fn latest_created_at(conn: &Connection, table: &str) -> Result<Option<i64>> {
+    let latest = conn.query_row(
+        &format!("SELECT MAX({}) FROM \"{}\"", SOURCE_CREATED_AT_EPOCH, table),
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(latest)
+}
+
+/// Rows created at or after the checkpoint bound to ?1 (all rows when NULL).
+/// Rows whose `created_at` is missing or unparseable can't be placed against
+/// a checkpoint, so every scan includes them; the signature check skips the
+/// ones already migrated.
+fn since_checkpoint() -> String {
+    format!("(?1 IS NULL OR {0} >= ?1 OR {0} IS NULL)", SOURCE_CREATED_AT_EPOCH)
+}
+
+/// Source rows past the checkpoint bound to ?1 (see `since_checkpoint`),
+/// skipping the first ?2 rows already scanned
+fn scan_query(table: &str) -> String {
+    format!(
+        "SELECT signature, burner, amount, memo, token, timestamp, memo_checked, created_at 
+         FROM \"{}\" 
+         WHERE {}
+         ORDER BY timestamp DESC
+         LIMIT -1 OFFSET ?2",
+        table,
+        since_checkpoint()
+    )
+}
+
+/// Oldest `created_at` among the rows a scan from `checkpoint` hasn't reached
+/// after `scanned` rows; None when nothing datable is left
+fn resume_checkpoint(conn: &Connection, table: &str, checkpoint: Option<i64>, scanned: u64) -> Result<Option<i64>> {
+    let resume = conn.query_row(
+        &format!(
+            "SELECT MIN(created_epoch) FROM (
+                 SELECT {0} AS created_epoch FROM \"{1}\"
+                 WHERE {2}
+                 ORDER BY timestamp DESC
+                 LIMIT -1 OFFSET ?2
+             )",
+            SOURCE_CREATED_AT_EPOCH,
+            table,
+            since_checkpoint()
+        ),
+        params![checkpoint, scanned as i64],
+        |row| row.get(0),
+    )?;
+    Ok(resume)
+}
+
+/// SQLITE_BUSY / SQLITE_LOCKED: another connection holds the source's lock
+fn is_busy(error: &rusqlite::Error) -> bool {
+    matches!(
+        error,
+        rusqlite::Error::SqliteFailure(failure, _)
+            if matches!(failure.code, rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+    )
+}
+
+fn ensure_table_exists(conn: &Connection, source: &Path, table: &str) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")?;
+    let tables = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    
+    if !tables.iter().any(|name| name.eq_ignore_ascii_case(table)) {
+        return Err(anyhow::anyhow!(
+            "Source table {:?} not found in {:?}; available tables: {}",
+            table,
+            source,
+            if tables.is_empty() { "(none)".to_string() } else { tables.join(", ") }
+        ));
+    }
+    Ok(())
+}
+
+/// Positions of the source columns in a CSV header
+struct CsvColumns {
+    signature: usize,
+    burner: usize,
+    amount: usize,
+    memo: Option<usize>,
+    token: Option<usize>,
+    timestamp: Option<usize>,
+    memo_checked: Option<usize>,
+    created_at: Option<usize>,
+}
+
+/// What a migration did: rows copied and rows left out, by reason
+#[derive(Debug, Default, Serialize)]
+pub struct MigrationReport {
+    pub migrated: usize,
+    pub skipped_existing: usize,
+    pub below_minimum: usize,
+    pub above_maximum: usize, // Over max_burn_amount, likely corrupt
+    pub filtered_by_memo: usize,
+    pub filtered_by_token: usize,
+    pub rejected_invalid: usize, // Malformed signatures
+}
+
+impl MigrationReport {
+    /// Rows left out for any reason
+    pub fn skipped(&self) -> usize {
+        self.skipped_existing
+            + self.below_minimum
+            + self.above_maximum
+            + self.filtered_by_memo
+            + self.filtered_by_token
+            + self.rejected_invalid
+    }
+    
+    fn add(&mut self, other: &MigrationReport) {
+        self.migrated += other.migrated;
+        self.skipped_existing += other.skipped_existing;
+        self.below_minimum += other.below_minimum;
+        self.above_maximum += other.above_maximum;
+        self.filtered_by_memo += other.filtered_by_memo;
+        self.filtered_by_token += other.filtered_by_token;
+        self.rejected_invalid += other.rejected_invalid;
+    }
+}
+
 // Temporary structure for source data
 #[derive(Debug)]
 struct BurnRecordSource {
@@ -390,4 +1065,158 @@ struct BurnRecordSource {
     timestamp: Option<DateTime<Utc>>,
     memo_checked: Option<String>,
     created_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+impl BurnRecordSource {
+    /// Whether this row should replace `other`, a row with the same signature
+    fn preferred_over(&self, other: &BurnRecordSource, rule: DedupeRule) -> bool {
+        match rule {
+            DedupeRule::HighestAmount => self.amount > other.amount,
+            DedupeRule::EarliestCreatedAt => self.created_at < other.created_at,
+        }
+    }
+    
+    fn into_new_record(self, raw_amount: u64) -> NewBurnRecord {
+        NewBurnRecord {
+            signature: self.signature,
+            burner: self.burner,
+            amount: raw_amount, // 直接存储：420690000 -> 420690000
+            memo: self.memo,
+            token: self.token,
+            timestamp: self.timestamp,
+            memo_checked: MemoCheck::from_column(self.memo_checked.as_deref()),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Config migrating `dir`/burns.db into `dir`/dest.db
+    fn test_config(dir: &Path) -> Config {
+        let mut config = Config::load().unwrap();
+        config.database_url = format!("sqlite:{}", dir.join("dest.db").display());
+        config.source_db_paths = vec![dir.join("burns.db")];
+        config
+    }
+    
+    /// A burns.db in `dir` with the source table and no rows
+    fn source_db(dir: &Path) -> Connection {
+        let source = Connection::open(dir.join("burns.db")).unwrap();
+        source.execute_batch(
+            "CREATE TABLE burns (signature TEXT, burner TEXT, amount INTEGER, memo TEXT, token TEXT,
+                                 timestamp, memo_checked TEXT, created_at)",
+        ).unwrap();
+        source
+    }
+    
+    /// Add a 500 solXEN burn with a fresh signature; the times are SQL
+    /// literals so they can be an epoch, date text, garbage or NULL
+    fn add_burn(source: &Connection, timestamp: &str, created_at: &str) {
+        source.execute(
+            &format!(
+                "INSERT INTO burns (signature, burner, amount, timestamp, created_at) VALUES (?1, 'burner-1', 500000000, {}, {})",
+                timestamp, created_at
+            ),
+            params![Signature::new_unique().to_string()],
+        ).unwrap();
+    }
+    
+    #[tokio::test]
+    async fn incremental_runs_pick_up_undated_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = source_db(dir.path());
+        add_burn(&source, "0", "1700000000");
+        add_burn(&source, "0", "NULL");
+        
+        let migrator = DatabaseMigrator::new(test_config(dir.path()));
+        let first = migrator.migrate(None).await.unwrap();
+        assert_eq!((first.migrated, first.skipped_existing), (2, 0));
+        
+        // Added between runs, after the checkpoint was set
+        add_burn(&source, "0", "1700000100");
+        add_burn(&source, "0", "NULL");
+        add_burn(&source, "0", "'not a date'");
+        
+        let second = migrator.migrate(None).await.unwrap();
+        assert_eq!(second.migrated, 3);
+        // The dated row at the checkpoint and the undated one are scanned again
+        assert_eq!(second.skipped_existing, 2);
+        
+        let stats = Database::new(&test_config(dir.path())).await.unwrap().get_statistics().await.unwrap();
+        assert_eq!(stats.total_records, 5);
+    }
+    
+    #[tokio::test]
+    async fn every_time_format_is_stored_the_same() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = source_db(dir.path());
+        // 2024-01-01 00:00:00 UTC, written the ways exports write it
+        let times = [
+            "1704067200",
+            "'2024-01-01 08:00:00'", // naive, in source_timezone
+            "'2024-01-01T08:00:00'",
+            "'2024-01-01 08:00:00+08:00'",
+            "'2024-01-01T00:00:00Z'",
+            "'2023-12-31T19:00:00-05:00'",
+        ];
+        for time in times {
+            add_burn(&source, time, time);
+        }
+        
+        let mut config = test_config(dir.path());
+        config.source_timezone = crate::config::parse_timezone("Asia/Shanghai").unwrap();
+        let report = DatabaseMigrator::new(config).migrate(None).await.unwrap();
+        assert_eq!(report.migrated, times.len());
+        
+        let dest = Connection::open(dir.path().join("dest.db")).unwrap();
+        let mut stmt = dest.prepare("SELECT DISTINCT timestamp, created_at FROM burn_records").unwrap();
+        let stored = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(stored.len(), 1, "times stored differently: {:?}", stored);
+        let (timestamp, created_at) = &stored[0];
+        assert_eq!(timestamp, created_at);
+        assert_eq!(
+            DateTime::parse_from_rfc3339(timestamp).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+    
+    #[tokio::test]
+    async fn migrates_from_a_differently_named_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = source_db(dir.path());
+        add_burn(&source, "0", "1700000000");
+        add_burn(&source, "0", "1700000100");
+        source.execute_batch("ALTER TABLE burns RENAME TO solxen_burns_v2").unwrap();
+        
+        let mut config = test_config(dir.path());
+        config.source_table = "solxen_burns_v2".to_string();
+        let report = DatabaseMigrator::new(config).migrate(None).await.unwrap();
+        assert_eq!(report.migrated, 2);
+        
+        // The default name no longer exists in this source
+        let error = DatabaseMigrator::new(test_config(dir.path())).migrate(None).await.unwrap_err();
+        assert!(error.to_string().contains("burns"), "{}", error);
+        
+        let mut config = test_config(dir.path());
+        config.source_table = "solxen_burns_v2; DROP TABLE solxen_burns_v2".to_string();
+        let error = DatabaseMigrator::new(config).migrate(None).await.unwrap_err();
+        assert!(error.to_string().starts_with("Invalid source table name"), "{}", error);
+    }
+    
+    #[test]
+    fn source_table_must_be_a_plain_identifier() {
+        for name in ["burns", "_burns", "Burns2024", "solxen_burns_v2"] {
+            assert_eq!(source_table_name(name).unwrap(), name);
+        }
+        for name in ["", "2burns", "burns; DROP TABLE burn_records", "burns\"", "main.burns", "burn-records", "burns "] {
+            assert!(source_table_name(name).is_err(), "{:?} was accepted", name);
+        }
+    }
+}
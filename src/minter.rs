@@ -1,62 +1,248 @@
-use anyhow::Result;
-use log::{error, info, warn};
-use solana_client::rpc_client::RpcClient;
+use anyhow::{bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use log::{debug, error, info, warn};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_config::RpcTransactionConfig,
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
 use solana_sdk::{
     commitment_config::CommitmentConfig,
+    hash::Hash,
     instruction::Instruction,
+    message::Message,
+    packet::PACKET_DATA_SIZE,
     pubkey::Pubkey,
-    signature::Keypair,
+    signature::{Keypair, Signature},
     signer::Signer,
-    system_instruction,
     transaction::Transaction,
 };
-use spl_token_2022::{
-    instruction as token_instruction,
-    state::Mint,
-};
+use solana_transaction_status::UiTransactionEncoding;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::instruction as token_instruction;
+use spl_token_2022::solana_program::program_option::COption;
+use spl_token_2022::state::{Account, Mint};
 use spl_associated_token_account::{
     get_associated_token_address_with_program_id,
-    instruction::create_associated_token_account,
+    instruction::create_associated_token_account_idempotent,
 };
+use std::collections::HashSet;
 use std::fs::File;
-use std::io::Read;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::path::Path;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, BufReader, Lines, Stdin};
+use tokio::sync::Semaphore;
+use tokio::task::{JoinError, JoinHandle, JoinSet};
 
-use crate::config::Config;
+use crate::config::{Config, DistributionMode};
 use crate::database::Database;
+use crate::logging;
+use crate::metrics::METRICS;
+use crate::progress::Progress;
+use crate::rpc::RpcPool;
 use crate::types::BurnRecord;
+use crate::hooks::{self, PostMintHook};
+
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Most accounts the RPC returns from one getMultipleAccounts call
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Below this the fee payer may not afford Token 2022 transactions (0.01 SOL)
+pub const MIN_AUTHORITY_BALANCE_LAMPORTS: u64 = 10_000_000;
+
+/// Secret key of the mint authority; takes precedence over `keypair_path`
+const MINT_AUTHORITY_ENV: &str = "SOLXEN_MINT_AUTHORITY";
+
+/// A confirmed mint, the raw units it minted and the fee it cost, when the
+/// fee could be looked up
+struct MintOutcome {
+    signature: String,
+    amount: u64,
+    fee_lamports: Option<u64>,
+}
+
+/// One line of a `mint --build-only` file: an unsigned mint transaction for a
+/// pending burn. The offline signer fills in `transaction` and the file goes
+/// back to `mint --submit`. Messages and transactions are bincode, then base64.
+#[derive(Serialize, Deserialize)]
+struct OfflineMint {
+    burn_signature: String,
+    burner: String,
+    recipient: String,
+    amount: u64, // Raw units to mint
+    signers: Vec<String>, // Keys that have to sign, fee payer first
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transaction: Option<String>,
+}
+
+/// Shortest pause a rate limit bumps the delay to, so a zero delay can grow
+const RATE_LIMIT_MIN_DELAY: Duration = Duration::from_millis(250);
+
+/// How much each mint that goes through shortens the delay
+const DELAY_DECREASE_STEP: Duration = Duration::from_millis(100);
+
+/// The pause between mints, adjusted AIMD style: a rate-limited mint doubles
+/// it, every mint that goes through takes a step off, always within
+/// `mint_delay_min_ms..=mint_delay_max_ms`. Without a `mint_delay_min_ms` it
+/// never drops below `mint_delay_ms`. Shared by all mint workers.
+struct AdaptiveDelay {
+    current_ms: AtomicU64,
+    min_ms: u64,
+    max_ms: u64,
+}
+
+impl AdaptiveDelay {
+    fn new(config: &Config) -> Self {
+        let min_ms = config.mint_delay_min_ms.unwrap_or(config.mint_delay_ms);
+        let max_ms = config.mint_delay_max_ms.max(min_ms);
+        AdaptiveDelay {
+            current_ms: AtomicU64::new(config.mint_delay_ms.clamp(min_ms, max_ms)),
+            min_ms,
+            max_ms,
+        }
+    }
+    
+    fn current(&self) -> Duration {
+        Duration::from_millis(self.current_ms.load(Ordering::SeqCst))
+    }
+    
+    /// Adjust the delay after a mint; `rate_limited` when the RPC refused it as too many requests
+    fn observe(&self, rate_limited: bool) {
+        let floor = (RATE_LIMIT_MIN_DELAY.as_millis() as u64).clamp(self.min_ms, self.max_ms);
+        let step = DELAY_DECREASE_STEP.as_millis() as u64;
+        let next = |current: u64| {
+            let next = if rate_limited {
+                current.saturating_mul(2).max(floor).min(self.max_ms)
+            } else {
+                current.saturating_sub(step).max(self.min_ms)
+            };
+            (next != current).then_some(next)
+        };
+        if let Ok(previous) = self.current_ms.fetch_update(Ordering::SeqCst, Ordering::SeqCst, next) {
+            let current = self.current_ms.load(Ordering::SeqCst);
+            if rate_limited {
+                warn!("🐢 RPC is rate limiting; mint delay raised from {} ms to {} ms", previous, current);
+            } else {
+                debug!("🐇 Mint delay lowered from {} ms to {} ms", previous, current);
+            }
+        }
+    }
+}
 
-pub struct TokenMinter<'a> {
-    config: &'a Config,
-    db: &'a Database,
-    rpc_client: RpcClient,
-    mint_authority: Option<Keypair>,
+/// What minting a batch of records is expected to cost its fee payer
+struct MintCost {
+    fee_lamports: u64,
+    rent_lamports: u64,
+    missing_accounts: usize, // Recipient token accounts the run creates
+    token_accounts: usize, // Distinct recipient token accounts
+}
+
+/// End state of one mint run. Of the `pending` records handed to the run,
+/// attempted ones either succeeded or failed and skipped ones stay pending
+/// for a later run, as do any left over by `--limit`.
+#[derive(Debug, Clone, Default)]
+pub struct MintSummary {
+    pub pending: usize,
+    pub attempted: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize, // Unmintable burner, above max_burn_amount, declined, or not started after Ctrl-C
+}
+
+impl MintSummary {
+    /// Count a finished mint worker: whether its mint landed, or `None` when
+    /// it never sent one. A panicking worker only loses its own record; the
+    /// rest keep running.
+    fn add(&mut self, result: Result<Option<bool>, JoinError>) {
+        let minted = match result {
+            Ok(minted) => minted,
+            Err(e) => {
+                METRICS.mint_failures_total.inc();
+                error!("❌ Mint worker stopped unexpectedly: {}", e);
+                Some(false)
+            }
+        };
+        match minted {
+            Some(true) => {
+                self.attempted += 1;
+                self.succeeded += 1;
+            }
+            Some(false) => {
+                self.attempted += 1;
+                self.failed += 1;
+            }
+            None => self.skipped += 1,
+        }
+    }
+    
+    pub fn log(&self) {
+        info!("📋 Processed {} of {} pending mints", self.attempted, self.pending);
+        info!("   Succeeded: {}", self.succeeded);
+        info!("   Failed: {}", self.failed);
+        info!("   Skipped: {}", self.skipped);
+    }
+}
+
+// Cheap to clone: every field is shared, so each mint worker gets its own handle
+#[derive(Clone)]
+pub struct TokenMinter {
+    config: Arc<Config>,
+    db: Database,
+    rpc: Arc<RpcPool>, // X1 endpoints, failing over between them
+    mint_authority: Option<Arc<Keypair>>,
+    fee_payer: Option<Arc<Keypair>>, // Pays instead of the mint authority when set
     token_mint: Pubkey,
+    transfer_source: Option<Pubkey>, // Set in transfer mode: tokens come from this account instead of mint_to
+    hooks: Arc<Vec<Box<dyn PostMintHook>>>, // Run after every mint, in order
+    hook_runs: Arc<Mutex<JoinSet<()>>>, // Hooks still running for mints already recorded
+    mint_delay: Arc<AdaptiveDelay>,
+    shutdown: Arc<AtomicBool>, // Set by Ctrl-C during a mint run; nothing is sent once it is
+}
+
+/// Aborts the task when dropped, so a listener lives only as long as the
+/// scope holding it, early returns included
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
 }
 
-impl<'a> TokenMinter<'a> {
-    pub async fn new(config: &'a Config, db: &'a Database) -> Result<Self> {
-        let rpc_client = RpcClient::new_with_commitment(
-            config.x1_rpc_url.clone(),
+impl TokenMinter {
+    pub async fn new(config: &Config, db: &Database) -> Result<Self> {
+        let rpc = RpcPool::new(
+            &config.x1_rpc_urls,
+            Duration::from_secs(config.rpc_timeout_secs),
             CommitmentConfig::confirmed(),
         );
         
-        match rpc_client.get_version() {
-            Ok(version) => info!("Connected to X1 testnet, version: {}", version.solana_core),
+        match rpc.call(|rpc| rpc.get_version()) {
+            Ok(version) => info!("Connected to X1 RPC {}, version: {}", rpc.client().url(), version.solana_core),
             Err(e) => {
-                error!("Failed to connect to X1 testnet: {}", e);
+                error!("Failed to connect to {}: {}", config.network, e);
                 return Err(e.into());
             }
         }
 
         let mint_authority = Self::load_keypair(&config.keypair_path)?;
+        let fee_payer = config.fee_payer_path.as_deref().map(read_keypair).transpose()?;
         let token_mint = Pubkey::from_str(&config.token_mint)?;
         
         // 验证这是一个 Token 2022 铸造账户
-        match rpc_client.get_account(&token_mint) {
+        match rpc.call(|rpc| rpc.get_account(&token_mint)) {
             Ok(mint_account) => {
-                info!("Token mint found on X1 testnet");
+                info!("Token mint found on {}", config.network);
                 info!("Token mint address: {}", token_mint);
                 info!("Token account owner: {}", mint_account.owner);
                 info!("Token account lamports: {}", mint_account.lamports);
@@ -71,121 +257,754 @@ impl<'a> TokenMinter<'a> {
                     warn!("   Actual: {}", mint_account.owner);
                 }
                 
-                // 尝试解析 Token 2022 mint 数据
-                if mint_account.data.len() >= 82 { // 最小 Token 2022 mint 大小
-                    info!("Mint account data length: {} bytes", mint_account.data.len());
-                } else {
-                    warn!("Mint account data seems too small for Token 2022");
+                // 解析 Token 2022 mint 数据（扩展以 TLV 形式跟在基础状态之后）
+                let mint_state = match StateWithExtensions::<Mint>::unpack(&mint_account.data) {
+                    Ok(state) => {
+                        let mint = state.base;
+                        info!("Mint decimals: {}", mint.decimals);
+                        info!(
+                            "Mint supply: {} ({} raw units)",
+                            Decimal::from_i128_with_scale(i128::from(mint.supply), u32::from(mint.decimals)),
+                            mint.supply
+                        );
+                        match state.get_extension_types() {
+                            Ok(extensions) if !extensions.is_empty() => info!("Mint extensions: {:?}", extensions),
+                            Ok(_) => {}
+                            Err(e) => warn!("Could not read mint extensions: {}", e),
+                        }
+                        // Every amount is scaled by token_decimals, so a mismatch misreports all of them
+                        if mint.decimals != config.token_decimals {
+                            if !config.skip_decimal_check {
+                                bail!(
+                                    "Mint {} has {} decimals but amounts are scaled by {} (token_decimals); \
+                                     pass --skip-decimal-check to continue anyway",
+                                    token_mint, mint.decimals, config.token_decimals
+                                );
+                            }
+                            warn!(
+                                "⚠️  Mint has {} decimals but token_decimals is {}; reported amounts will be off",
+                                mint.decimals, config.token_decimals
+                            );
+                        }
+                        Some(mint)
+                    }
+                    Err(e) => {
+                        warn!("Could not parse Token 2022 mint data ({} bytes): {}", mint_account.data.len(), e);
+                        None
+                    }
+                };
+                
+                // Every mint_to would fail at send time with the wrong key, so stop here.
+                // Transfers are authorized by the source account's owner instead.
+                let check_authority = config.distribution_mode == DistributionMode::Mint;
+                match (&mint_authority, mint_state.filter(|_| check_authority)) {
+                    (Some(keypair), Some(mint)) => match mint.mint_authority {
+                        COption::Some(authority) if authority == keypair.pubkey() => {
+                            info!("✅ Loaded keypair is the mint authority");
+                        }
+                        COption::Some(authority) => bail!(
+                            "Loaded keypair {} is not the mint authority of {} (expected {})",
+                            keypair.pubkey(),
+                            token_mint,
+                            authority
+                        ),
+                        COption::None => bail!(
+                            "Token mint {} has no mint authority, so no more tokens can be minted",
+                            token_mint
+                        ),
+                    },
+                    (Some(_), None) if check_authority => warn!("Skipping the mint authority check"),
+                    (Some(_), None) => {}
+                    (None, _) => {}
                 }
             }
             Err(e) => {
-                error!("Failed to find token mint {} on X1 testnet: {}", token_mint, e);
+                error!("Failed to find token mint {} on {}: {}", token_mint, config.network, e);
                 return Err(e.into());
             }
         }
         
+        let transfer_source = match config.distribution_mode {
+            DistributionMode::Mint => None,
+            DistributionMode::Transfer => {
+                let source = Pubkey::from_str(config.transfer_source.as_deref().unwrap_or_default())?;
+                Self::check_transfer_source(&rpc, &source, &token_mint, mint_authority.as_ref())?;
+                Some(source)
+            }
+        };
+        
         if let Some(ref keypair) = mint_authority {
             info!("Loaded mint authority: {}", keypair.pubkey());
             
-            match rpc_client.get_balance(&keypair.pubkey()) {
+            // Whoever pays the fees needs the SOL
+            let (payer_label, payer) = match &fee_payer {
+                Some(fee_payer) => {
+                    info!("Loaded fee payer: {}", fee_payer.pubkey());
+                    ("Fee payer", fee_payer)
+                }
+                None => ("Mint authority", keypair),
+            };
+            match rpc.call(|rpc| rpc.get_balance(&payer.pubkey())) {
                 Ok(balance) => {
                     let balance_sol = balance as f64 / 1_000_000_000.0;
-                    info!("Mint authority balance: {:.4} SOL", balance_sol);
+                    info!("{} balance: {:.4} SOL", payer_label, balance_sol);
                     
-                    if balance_sol < 0.01 {
+                    if balance < MIN_AUTHORITY_BALANCE_LAMPORTS {
                         warn!("Low SOL balance! May not be enough for Token 2022 transactions");
                     }
                 }
                 Err(e) => {
-                    warn!("Could not check mint authority balance: {}", e);
+                    warn!("Could not check {} balance: {}", payer_label.to_lowercase(), e);
                 }
             }
         } else {
             warn!("No mint authority loaded - running in simulation mode");
         }
+        
+        if !config.recipient_overrides.is_empty() {
+            info!("Loaded {} recipient overrides", config.recipient_overrides.len());
+        }
+        
+        let hooks = hooks::from_config(config)?;
 
         Ok(TokenMinter {
-            config,
-            db,
-            rpc_client,
-            mint_authority,
+            config: Arc::new(config.clone()),
+            db: db.clone(),
+            rpc: Arc::new(rpc),
+            mint_authority: mint_authority.map(Arc::new),
+            fee_payer: fee_payer.map(Arc::new),
             token_mint,
+            transfer_source,
+            hooks: Arc::new(hooks),
+            hook_runs: Arc::default(),
+            mint_delay: Arc::new(AdaptiveDelay::new(config)),
+            shutdown: Arc::new(AtomicBool::new(false)),
         })
     }
     
-    fn load_keypair(path: &std::path::Path) -> Result<Option<Keypair>> {
+    /// Transfer mode: the source must be a token account of this mint owned by
+    /// the loaded authority, since the authority signs every transfer
+    fn check_transfer_source(
+        rpc: &RpcPool,
+        source: &Pubkey,
+        token_mint: &Pubkey,
+        authority: Option<&Keypair>,
+    ) -> Result<()> {
+        let account = rpc
+            .call(|rpc| rpc.get_account(source))
+            .with_context(|| format!("Transfer source {} not found", source))?;
+        let state = StateWithExtensions::<Account>::unpack(&account.data)
+            .map_err(|e| anyhow::anyhow!("Transfer source {} is not a token account: {}", source, e))?;
+        let source_account = state.base;
+        
+        if source_account.mint != *token_mint {
+            bail!("Transfer source {} holds {}, not {}", source, source_account.mint, token_mint);
+        }
+        if let Some(authority) = authority {
+            if source_account.owner != authority.pubkey() {
+                bail!(
+                    "Transfer source {} is owned by {}, not by the loaded keypair {}",
+                    source,
+                    source_account.owner,
+                    authority.pubkey()
+                );
+            }
+        }
+        info!("✅ Transfer source: {}", source);
+        info!("   Balance: {} solXEN ({} raw units)", raw_to_solxen(source_account.amount), source_account.amount);
+        Ok(())
+    }
+    
+    /// Transfer mode: stop before sending anything when the source can't
+    /// cover every record of the run
+    fn check_transfer_balance(&self, records: &[BurnRecord]) -> Result<()> {
+        let Some(source) = &self.transfer_source else {
+            return Ok(());
+        };
+        let mut needed: u64 = 0;
+        for record in records {
+            needed = needed.saturating_add(self.config.mint_amount_for(record.amount)?);
+        }
+        let balance: u64 = self.rpc.call(|rpc| rpc.get_token_account_balance(source))?.amount.parse()?;
+        if balance < needed {
+            bail!(
+                "Transfer source {} holds {} solXEN but this run needs {} solXEN; top it up or use --limit",
+                source,
+                raw_to_solxen(balance),
+                raw_to_solxen(needed)
+            );
+        }
+        Ok(())
+    }
+    
+    /// Load the mint authority from `SOLXEN_MINT_AUTHORITY` if set, otherwise
+    /// from the file at `path`. Both accept a JSON byte array (as written by
+    /// `solana-keygen`) or a base58-encoded secret key.
+    pub fn load_keypair(path: &std::path::Path) -> Result<Option<Keypair>> {
+        Self::load_keypair_from(std::env::var(MINT_AUTHORITY_ENV).ok(), path)
+    }
+    
+    /// `load_keypair` with the value of `SOLXEN_MINT_AUTHORITY` passed in
+    fn load_keypair_from(secret: Option<String>, path: &std::path::Path) -> Result<Option<Keypair>> {
+        if let Some(secret) = secret.filter(|value| !value.trim().is_empty()) {
+            let keypair = parse_keypair(&secret)
+                .map_err(|e| anyhow::anyhow!("Invalid {}: {}", MINT_AUTHORITY_ENV, e))?;
+            info!("Using mint authority from {}", MINT_AUTHORITY_ENV);
+            return Ok(Some(keypair));
+        }
+        
         if !path.exists() {
             warn!("Keypair file not found: {:?}", path);
             return Ok(None);
         }
         
-        let mut file = File::open(path)?;
-        let mut data = Vec::new();
-        file.read_to_end(&mut data)?;
+        read_keypair(path).map(Some)
+    }
+    
+    /// Mint the pending burns (of `burner` only, when given) and report how
+    /// each of them ended up
+    pub async fn process_pending_mints(&mut self, burner: Option<&str>) -> Result<MintSummary> {
+        // Settle transactions left in flight by a previous run before selecting
+        // work, so a crash between confirmation and mark_as_minted never re-mints
+        let in_flight = self.reconcile_pending_log().await?;
+        
+        let pending_records = match burner {
+            Some(burner) => self.db.get_pending_mints_for(burner, self.config.min_burn_amount).await?,
+            None => self.db.get_pending_mints(self.config.min_burn_amount).await?,
+        };
+        let pending_records: Vec<BurnRecord> = pending_records
+            .into_iter()
+            .filter(|record| !in_flight.contains(&record.signature))
+            .collect();
+        METRICS.pending_mints.set(pending_records.len() as i64);
+        
+        if pending_records.is_empty() {
+            match burner {
+                Some(burner) => info!("✅ No pending mint operations found for {}", burner),
+                None => info!("✅ No pending mint operations found"),
+            }
+            return Ok(MintSummary::default());
+        }
         
-        let keypair_bytes: Vec<u8> = serde_json::from_slice(&data)?;
-        let keypair = Keypair::from_bytes(&keypair_bytes)?;
+        let total_pending = pending_records.len();
+        info!("🚀 Starting mint operations");
+        info!("   Found {} pending mint operations", total_pending);
+        if let Some(burner) = burner {
+            info!("   Burner: {}", burner);
+        }
+        info!("   Minimum burn amount: {} solXEN", self.config.min_burn_amount_solxen());
         
-        Ok(Some(keypair))
+        self.mint_records(pending_records).await
     }
     
-    pub async fn process_pending_mints(&mut self) -> Result<()> {
-        let pending_records = self.db.get_pending_mints(self.config.min_burn_amount).await?;
+    /// `mint --build-only`: write an unsigned mint transaction for every
+    /// pending burn to `path`, one JSON object per line, so they can be signed
+    /// on a machine that holds the authority key. Nothing is sent or recorded.
+    pub async fn build_offline_mints(&self, burner: Option<&str>, path: &Path) -> Result<usize> {
+        let in_flight = self.reconcile_pending_log().await?;
+        let mut records = match burner {
+            Some(burner) => self.db.get_pending_mints_for(burner, self.config.min_burn_amount).await?,
+            None => self.db.get_pending_mints(self.config.min_burn_amount).await?,
+        };
+        let pending = records.len();
+        records.retain(|record| {
+            !in_flight.contains(&record.signature)
+                && recipient_error(self.config.recipient_for(&record.burner)).is_none()
+                && !self.config.exceeds_max_burn(record.amount)
+        });
+        if records.len() < pending {
+            warn!("⚠️  Left out {} burns that are in flight, can't receive tokens or are above the maximum", pending - records.len());
+        }
+        if let Some(limit) = self.config.mint_limit {
+            records.truncate(limit);
+        }
         
-        if pending_records.is_empty() {
-            info!("✅ No pending mint operations found");
+        let authority = self.signing_authority()?;
+        let payer = self.fee_payer.as_ref().map_or(authority, |payer| payer.pubkey());
+        let recent_blockhash = self.rpc.call(|rpc| rpc.get_latest_blockhash())?;
+        
+        let mut lines = String::new();
+        for record in &records {
+            let (message, amount) = self.unsigned_mint_message(record, &payer, &authority, recent_blockhash)?;
+            let mut signers = vec![payer.to_string()];
+            if authority != payer {
+                signers.push(authority.to_string());
+            }
+            let line = OfflineMint {
+                burn_signature: record.signature.clone(),
+                burner: record.burner.clone(),
+                recipient: self.config.recipient_for(&record.burner).to_string(),
+                amount,
+                signers,
+                message: BASE64.encode(bincode::serialize(&message)?),
+                transaction: None,
+            };
+            lines.push_str(&serde_json::to_string(&line)?);
+            lines.push('\n');
+        }
+        std::fs::write(path, lines)
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+        
+        info!("📝 Wrote {} unsigned mint transactions to {}", records.len(), path.display());
+        info!("   Authority: {}", authority);
+        info!("   Blockhash: {} (valid for about a minute; sign and submit before it expires)", recent_blockhash);
+        Ok(records.len())
+    }
+    
+    /// `mint --submit`: broadcast the signed transactions of a `--build-only`
+    /// file and record them like regular mints. Each transaction must be the
+    /// exact one this host would build for a still-pending burn; anything else
+    /// is refused. With a fee payer keypair loaded its signature is added here.
+    pub async fn submit_offline_mints(&self, path: &Path) -> Result<MintSummary> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str::<OfflineMint>(line)
+                    .map_err(|e| anyhow::anyhow!("{} line {}: {}", path.display(), index + 1, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        
+        let in_flight = self.reconcile_pending_log().await?;
+        let authority = self.signing_authority()?;
+        let mut summary = MintSummary { pending: entries.len(), ..MintSummary::default() };
+        info!("📤 Submitting {} signed mint transactions from {}", entries.len(), path.display());
+        
+        for entry in entries {
+            let record = self.db.get_pending_mints_for(&entry.burner, 0).await?
+                .into_iter()
+                .find(|record| record.signature == entry.burn_signature);
+            let Some(record) = record.filter(|record| !in_flight.contains(&record.signature)) else {
+                warn!("⏭️  Burn {} is no longer pending, skipping", entry.burn_signature);
+                summary.skipped += 1;
+                continue;
+            };
+            let Some(transaction) = &entry.transaction else {
+                warn!("⏭️  Burn {} has no signed transaction, skipping", entry.burn_signature);
+                summary.skipped += 1;
+                continue;
+            };
+            
+            info!("Processing signed mint: {} -> {} solXEN", record.burner, raw_to_solxen(entry.amount));
+            let result = async {
+                let mut transaction: Transaction = bincode::deserialize(&BASE64.decode(transaction)?)?;
+                let payer = transaction.message.account_keys.first().copied().unwrap_or_default();
+                let (expected, amount) =
+                    self.unsigned_mint_message(&record, &payer, &authority, transaction.message.recent_blockhash)?;
+                if transaction.message != expected {
+                    bail!("Signed transaction for burn {} is not the mint this host would send", record.signature);
+                }
+                if let Some(fee_payer) = self.fee_payer.as_deref().filter(|fee_payer| fee_payer.pubkey() == payer) {
+                    let blockhash = transaction.message.recent_blockhash;
+                    transaction.try_partial_sign(&[fee_payer], blockhash)?;
+                }
+                transaction.verify().context("Transaction is not fully signed")?;
+                self.send_and_confirm(&record, &transaction, amount).await
+            }.await;
+            summary.attempted += 1;
+            if self.record_outcome(&record, result).await {
+                summary.succeeded += 1;
+            } else {
+                summary.failed += 1;
+            }
+            println!(); // Add blank line between transactions
+        }
+        
+        self.join_hooks().await;
+        Ok(summary)
+    }
+    
+    /// Key that authorizes mints: the mint authority, or in transfer mode the
+    /// owner of the transfer source, read from the chain so no keypair is needed
+    fn signing_authority(&self) -> Result<Pubkey> {
+        if let Some(source) = &self.transfer_source {
+            let data = self.rpc.call(|rpc| rpc.get_account_data(source))?;
+            let account = StateWithExtensions::<Account>::unpack(&data)
+                .map_err(|e| anyhow::anyhow!("Transfer source {} is not a token account: {}", source, e))?;
+            return Ok(account.base.owner);
+        }
+        let data = self.rpc.call(|rpc| rpc.get_account_data(&self.token_mint))?;
+        let mint = StateWithExtensions::<Mint>::unpack(&data)
+            .map_err(|e| anyhow::anyhow!("Could not parse token mint {}: {}", self.token_mint, e))?;
+        match mint.base.mint_authority {
+            COption::Some(authority) => Ok(authority),
+            COption::None => bail!("Token mint {} has no mint authority, so no more tokens can be minted", self.token_mint),
+        }
+    }
+    
+    /// The message `mint_tokens` would sign for `record`, and the raw units it mints
+    fn unsigned_mint_message(
+        &self,
+        record: &BurnRecord,
+        payer: &Pubkey,
+        authority: &Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<(Message, u64)> {
+        let recipient_address = self.config.recipient_for(&record.burner);
+        if let Some(reason) = recipient_error(recipient_address) {
+            bail!(reason);
+        }
+        if self.config.exceeds_max_burn(record.amount) {
+            bail!("Burn of {} solXEN is above max_burn_amount", record.amount_as_decimal());
+        }
+        let recipient = Pubkey::from_str(recipient_address)?;
+        let amount = self.config.mint_amount_for(record.amount)?;
+        
+        let mut instructions = self.mint_instructions(record, &recipient, payer, authority, amount)?;
+        let mut message = Message::new_with_blockhash(&instructions, Some(payer), &recent_blockhash);
+        // Same rule as mint_tokens: drop the burn memo when it doesn't fit
+        if self.config.include_burn_memo && transaction_size(&Transaction::new_unsigned(message.clone())) > PACKET_DATA_SIZE {
+            instructions.pop();
+            message = Message::new_with_blockhash(&instructions, Some(payer), &recent_blockhash);
+        }
+        Ok((message, amount))
+    }
+    
+    /// Estimate what minting every pending burn would cost, without sending
+    /// anything: the network fee of each mint transaction plus rent for the
+    /// token accounts that don't exist yet. No priority fee is set on mint
+    /// transactions, so none is added.
+    pub async fn estimate_pending_mints(&self, burner: Option<&str>) -> Result<()> {
+        let Some(mint_authority) = self.mint_authority.as_ref() else {
+            bail!("--estimate needs the mint authority keypair to build the transactions");
+        };
+        let payer = self.fee_payer.as_deref().unwrap_or(mint_authority);
+        
+        let mut records = match burner {
+            Some(burner) => self.db.get_pending_mints_for(burner, self.config.min_burn_amount).await?,
+            None => self.db.get_pending_mints(self.config.min_burn_amount).await?,
+        };
+        let pending = records.len();
+        records.retain(|record| {
+            recipient_error(self.config.recipient_for(&record.burner)).is_none() && !self.config.exceeds_max_burn(record.amount)
+        });
+        let skipped = pending - records.len();
+        if let Some(limit) = self.config.mint_limit {
+            records.truncate(limit);
+        }
+        
+        if records.is_empty() {
+            info!("✅ No pending mint operations to estimate");
             return Ok(());
         }
         
-        info!("🚀 Starting mint operations");
-        info!("   Found {} pending mint operations", pending_records.len());
-        info!("   Minimum burn amount: {} solXEN", self.config.min_burn_amount as f64 / 1_000_000.0);
+        info!("🧮 Estimating the cost of {} mints", records.len());
+        let MintCost { fee_lamports, rent_lamports, missing_accounts, token_accounts } =
+            self.estimate_cost(&records, &payer.pubkey(), &mint_authority.pubkey())?;
+        let total_lamports = fee_lamports + rent_lamports;
+        
+        info!("   Transaction fees: {} lamports", fee_lamports);
+        info!("   Token accounts to create: {} of {}", missing_accounts, token_accounts);
+        info!("   Token account rent: {} lamports", rent_lamports);
+        info!("   Estimated total: {} lamports ({} SOL)", total_lamports, lamports_to_sol(total_lamports));
+        info!("   Paid by: {}", payer.pubkey());
+        if skipped > 0 {
+            info!("   {} pending burns would be skipped (unmintable recipient or above max_burn_amount)", skipped);
+        }
+        Ok(())
+    }
+    
+    /// Network fees of minting `records` plus rent for the recipient token
+    /// accounts that don't exist yet. Every mint transaction has the same
+    /// signers, so the fee is looked up once, for the first record's message.
+    fn estimate_cost(&self, records: &[BurnRecord], payer: &Pubkey, authority: &Pubkey) -> Result<MintCost> {
+        let token_program_id = spl_token_2022::id();
+        let mut token_accounts = Vec::new();
+        for record in records {
+            let recipient = Pubkey::from_str(self.config.recipient_for(&record.burner))?;
+            token_accounts.push(get_associated_token_address_with_program_id(&recipient, &self.token_mint, &token_program_id));
+        }
+        
+        let fee_lamports = match records.first() {
+            Some(record) => {
+                let recent_blockhash = self.rpc.call(|rpc| rpc.get_latest_blockhash())?;
+                let (message, _) = self.unsigned_mint_message(record, payer, authority, recent_blockhash)?;
+                self.rpc.call(|rpc| rpc.get_fee_for_message(&message))? * records.len() as u64
+            }
+            None => 0,
+        };
+        
+        // Several burns of one wallet share a token account, created once
+        token_accounts.sort();
+        token_accounts.dedup();
+        let mut missing_accounts = 0;
+        for chunk in token_accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.rpc.call(|rpc| rpc.get_multiple_accounts(chunk))?;
+            missing_accounts += accounts.iter().filter(|account| account.is_none()).count();
+        }
+        
+        let rent_lamports = if missing_accounts > 0 {
+            let account_len = self.token_account_len()?;
+            let per_account = self.rpc.call(|rpc| rpc.get_minimum_balance_for_rent_exemption(account_len))?;
+            per_account * missing_accounts as u64
+        } else {
+            0
+        };
+        Ok(MintCost { fee_lamports, rent_lamports, missing_accounts, token_accounts: token_accounts.len() })
+    }
+    
+    /// Stop before sending anything when whoever pays the fees can't cover the
+    /// estimated cost of the run, instead of failing every mint at send time
+    fn check_payer_balance(&self, records: &[BurnRecord]) -> Result<()> {
+        let Some(mint_authority) = self.mint_authority.as_ref() else {
+            return Ok(());
+        };
+        if self.config.ignore_balance || records.is_empty() {
+            return Ok(());
+        }
+        let (payer_label, payer) = match &self.fee_payer {
+            Some(fee_payer) => ("Fee payer", fee_payer.pubkey()),
+            None => ("Mint authority", mint_authority.pubkey()),
+        };
+        let cost = self.estimate_cost(records, &payer, &mint_authority.pubkey())
+            .context("Could not estimate the cost of the run; pass --ignore-balance to mint anyway")?;
+        let needed = cost.fee_lamports + cost.rent_lamports;
+        let balance = self.rpc.call(|rpc| rpc.get_balance(&payer))?;
+        if balance < needed {
+            bail!(
+                "{} {} holds {} SOL but minting {} records needs about {} SOL ({} in fees, {} in token account rent); \
+                 add {} SOL, mint fewer with --limit, or pass --ignore-balance",
+                payer_label,
+                payer,
+                lamports_to_sol(balance),
+                records.len(),
+                lamports_to_sol(needed),
+                lamports_to_sol(cost.fee_lamports),
+                lamports_to_sol(cost.rent_lamports),
+                lamports_to_sol(needed - balance)
+            );
+        }
+        info!("✅ {} balance covers the estimated {} SOL for this run", payer_label, lamports_to_sol(needed));
+        Ok(())
+    }
+    
+    /// Size of a recipient token account for this mint: Token 2022 accounts
+    /// carry the extensions their mint requires, plus ImmutableOwner for ATAs
+    fn token_account_len(&self) -> Result<usize> {
+        let mint_account = self.rpc.call(|rpc| rpc.get_account(&self.token_mint))?;
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_account.data)?;
+        let mut extensions = ExtensionType::get_required_init_account_extensions(&mint.get_extension_types()?);
+        extensions.push(ExtensionType::ImmutableOwner);
+        Ok(ExtensionType::try_calculate_account_len::<Account>(&extensions)?)
+    }
+    
+    /// Retry burns whose earlier mint attempts failed. Burns that already
+    /// failed `max_mint_attempts` times are left alone unless `force` is set.
+    pub async fn remint_failed(&mut self, burner: Option<&str>, force: bool) -> Result<MintSummary> {
+        let in_flight = self.reconcile_pending_log().await?;
+        let failed = self.db.get_failed_mints(burner).await?;
+        
+        let mut records = Vec::new();
+        let mut capped = 0;
+        for failed_mint in failed {
+            if in_flight.contains(&failed_mint.record.signature) {
+                continue;
+            }
+            if !force && failed_mint.failed_attempts >= i64::from(self.config.max_mint_attempts) {
+                capped += 1;
+                warn!(
+                    "Skipping {}: already failed {} times (use --force to retry anyway)",
+                    failed_mint.record.signature, failed_mint.failed_attempts
+                );
+                continue;
+            }
+            records.push(failed_mint.record);
+        }
+        
+        if records.is_empty() {
+            info!("✅ No failed mints to retry");
+            if capped > 0 {
+                info!("   {} failed mints are over the {} attempt cap", capped, self.config.max_mint_attempts);
+            }
+            return Ok(MintSummary::default());
+        }
+        
+        info!("🔁 Retrying {} failed mints", records.len());
+        let summary = self.mint_records(records).await?;
+        if capped > 0 {
+            info!("   {} failed mints skipped by the {} attempt cap", capped, self.config.max_mint_attempts);
+        }
+        Ok(summary)
+    }
+    
+    /// Mint the given records on the worker pool and log the updated statistics
+    async fn mint_records(&self, records: Vec<BurnRecord>) -> Result<MintSummary> {
+        let pending = records.len();
+        
+        // Off-curve burners can never receive tokens, so retrying them is pointless;
+        // they are left pending and reported here for manual triage
+        let (records, unmintable): (Vec<BurnRecord>, Vec<BurnRecord>) = records
+            .into_iter()
+            .partition(|record| recipient_error(self.config.recipient_for(&record.burner)).is_none());
+        for record in &unmintable {
+            error!(
+                "❌ Skipping burn {}: {}",
+                record.signature,
+                recipient_error(self.config.recipient_for(&record.burner)).unwrap_or_default()
+            );
+        }
+        if !unmintable.is_empty() {
+            warn!("⚠️  Skipped {} burns whose burner cannot receive tokens", unmintable.len());
+        }
+        
+        // An implausibly large burn is more likely a corrupt source row than a
+        // real one; minting it would inflate the supply, so it needs --force
+        let (records, above_max): (Vec<BurnRecord>, Vec<BurnRecord>) =
+            records.into_iter().partition(|record| !self.config.exceeds_max_burn(record.amount));
+        for record in &above_max {
+            warn!(
+                "⚠️  Skipping burn {}: {} solXEN is above the {} solXEN maximum",
+                record.signature,
+                record.amount_as_decimal(),
+                raw_to_solxen(self.config.max_burn_amount.unwrap_or_default())
+            );
+        }
+        if !above_max.is_empty() {
+            warn!("⚠️  Skipped {} burns above max_burn_amount; pass --force to mint them", above_max.len());
+        }
+        
+        // Cut only after the skips above, so the limit isn't spent on burns
+        // that were never going to be minted. Records come fresh burns first,
+        // then ones with failed attempts, each oldest first.
+        let mut records = records;
+        let eligible = records.len();
+        if let Some(limit) = self.config.mint_limit.filter(|limit| *limit < eligible) {
+            records.truncate(limit);
+            info!("   Limit: {} records this run, {} more stay pending", limit, eligible - limit);
+        }
+        
         info!("   Token mint address: {}", self.config.token_mint);
         
         if self.mint_authority.is_some() {
-            info!("   Mode: REAL MINTING");
+            self.check_transfer_balance(&records)?;
+            self.check_payer_balance(&records)?;
+            match &self.transfer_source {
+                Some(source) => info!("   Mode: REAL TRANSFERS from {}", source),
+                None => info!("   Mode: REAL MINTING"),
+            }
         } else {
             info!("   Mode: SIMULATION (no keypair loaded)");
         }
         
-        println!(""); // Add blank line for readability
+        println!(); // Add blank line for readability
         
-        for record in pending_records {
-            info!(
-                "Processing mint: {} -> {} solXEN (raw: {})",
-                record.burner, 
-                record.amount_as_decimal(),
-                record.amount
-            );
-            
-            match self.mint_tokens(&record).await {
-                Ok(signature) => {
-                    info!("✅ Mint transaction successful!");
-                    info!("   Burner: {}", record.burner);
-                    info!("   Amount: {} solXEN ({} raw units)", record.amount_as_decimal(), record.amount);
-                    info!("   Burn Signature: {}", record.signature);
-                    info!("   Mint Signature: {}", signature);
-                    info!("   X1 Explorer: https://explorer.x1-testnet.xen.network/tx/{}", signature);
-                    
-                    if let Err(e) = self.db.mark_as_minted(&record.signature, &signature).await {
-                        error!("❌ Failed to update mint status in database: {}", e);
-                    } else {
-                        info!("✅ Database updated successfully");
-                    }
+        // Prompts can't interleave, so confirming each mint means minting one at a time
+        let concurrency = if self.config.confirm_each { 1 } else { self.config.mint_concurrency.max(1) };
+        if concurrency > 1 {
+            info!("   Concurrency: {} workers", concurrency);
+        }
+        info!(
+            "   Mint delay: {} ms, adapting between {} and {} ms",
+            self.mint_delay.current().as_millis(),
+            self.mint_delay.min_ms,
+            self.mint_delay.max_ms
+        );
+        
+        // Each worker holds a permit for its mint plus the pause after it, so at
+        // most `concurrency` transactions are in flight and each respects the delay
+        let semaphore = Arc::new(Semaphore::new(concurrency));
+        let mut workers = JoinSet::new();
+        let total = records.len();
+        let mut progress = Progress::new("Minting", total as u64);
+        let mut summary = MintSummary {
+            pending,
+            skipped: unmintable.len() + above_max.len(),
+            ..MintSummary::default()
+        };
+        
+        // Ctrl-C stops handing out new records and keeps workers that have not
+        // sent yet from sending; mints already sent run to completion, including
+        // mark_as_minted, so nothing is left half-recorded. The listener only
+        // lives for the loop below.
+        let shutdown = self.shutdown.clone();
+        shutdown.store(false, Ordering::SeqCst);
+        let shutdown_listener = AbortOnDrop(tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    warn!("🛑 Shutdown requested, finishing current mint then exiting");
+                    shutdown.store(true, Ordering::SeqCst);
                 }
-                Err(e) => {
-                    error!("❌ Mint failed for {}: {}", record.burner, e);
-                    error!("   Burn Signature: {}", record.signature);
-                    error!("   Amount: {} solXEN ({} raw units)", record.amount_as_decimal(), record.amount);
+            }
+        }));
+        
+        let mut answers = self.config.confirm_each.then(|| BufReader::new(tokio::io::stdin()).lines());
+        let mut started = 0;
+        for (index, record) in records.into_iter().enumerate() {
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            let permit = semaphore.clone().acquire_owned().await?;
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            if let Some(answers) = answers.as_mut() {
+                let confirmation = self.confirm_mint(&record, answers).await?;
+                // Ctrl-C while the prompt was open wins over the answer
+                if shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                match confirmation {
+                    Confirmation::Mint => {}
+                    Confirmation::Skip => {
+                        info!("⏭️  Skipped burn {}; it stays pending", record.signature);
+                        started += 1;
+                        summary.skipped += 1;
+                        progress.inc();
+                        continue;
+                    }
+                    Confirmation::Quit => {
+                        info!("🛑 Stopping at your request");
+                        break;
+                    }
                 }
             }
+            started += 1;
             
-            // Wait between transactions to avoid rate limiting
-            tokio::time::sleep(Duration::from_secs(2)).await;
-            println!(""); // Add blank line between transactions
+            // Count workers that finished while waiting, so progress moves as mints land
+            while let Some(result) = workers.try_join_next() {
+                summary.add(result);
+                progress.inc();
+            }
+            
+            let minter = self.clone();
+            workers.spawn(async move {
+                let minted = logging::with_mint_context(
+                    &record.burner,
+                    &record.signature,
+                    minter.process_record(&record),
+                ).await;
+                
+                // Wait between transactions to avoid rate limiting; nothing
+                // follows the last record or one that was never sent, so
+                // those don't wait
+                let mint_delay = minter.mint_delay.current();
+                if !mint_delay.is_zero() && index + 1 < total && minted.is_some() {
+                    tokio::time::sleep(mint_delay).await;
+                }
+                println!(); // Add blank line between transactions
+                drop(permit);
+                minted
+            });
+        }
+        
+        while let Some(result) = workers.join_next().await {
+            summary.add(result);
+            progress.inc();
         }
+        progress.finish();
+        drop(shutdown_listener);
+        self.join_hooks().await;
         
-        info!("🏁 Mint operations completed");
+        if started < total {
+            summary.skipped += total - started;
+            warn!("🛑 Stopped after {} of {} records; the rest stay pending", started, total);
+        } else {
+            info!("🏁 Mint operations completed");
+        }
         
         // Get updated statistics
         if let Ok(stats) = self.db.get_statistics().await {
@@ -196,22 +1015,242 @@ impl<'a> TokenMinter<'a> {
             info!("   Total minted: {} solXEN", stats.total_minted_amount);
         }
         
-        Ok(())
+        Ok(summary)
+    }
+    
+    /// `--confirm-each`: show what is about to be sent and ask whether to send
+    /// it. Anything but y, n or q asks again; closing stdin quits.
+    async fn confirm_mint(&self, record: &BurnRecord, answers: &mut Lines<BufReader<Stdin>>) -> Result<Confirmation> {
+        let action = if self.transfer_source.is_some() { "Transfer" } else { "Mint" };
+        let amount = self.config.mint_amount_for(record.amount)?;
+        println!("   Burn:      {}", record.signature);
+        println!("   Burner:    {}", record.burner);
+        println!("   Recipient: {}", self.config.recipient_for(&record.burner));
+        println!("   Amount:    {} solXEN (burned {} solXEN)", raw_to_solxen(amount), record.amount_as_decimal());
+        loop {
+            print!("{} this burn? [y]es / [n]o, skip it / [q]uit: ", action);
+            std::io::stdout().flush()?;
+            let answer = tokio::select! {
+                answer = answers.next_line() => answer?,
+                // The shutdown listener keeps Ctrl-C from killing the process,
+                // so a prompt waiting on stdin has to watch for it as well
+                _ = tokio::signal::ctrl_c() => {
+                    println!();
+                    self.shutdown.store(true, Ordering::SeqCst);
+                    return Ok(Confirmation::Quit);
+                }
+            };
+            let answer = match answer {
+                Some(answer) => answer,
+                None => return Ok(Confirmation::Quit),
+            };
+            match answer.trim().to_ascii_lowercase().as_str() {
+                "y" | "yes" => return Ok(Confirmation::Mint),
+                "n" | "no" => return Ok(Confirmation::Skip),
+                "q" | "quit" => return Ok(Confirmation::Quit),
+                _ => {}
+            }
+        }
+    }
+    
+    /// Mint one record and record the outcome; returns whether the mint
+    /// landed, or `None` when shutdown was requested before it was sent
+    async fn process_record(&self, record: &BurnRecord) -> Option<bool> {
+        // A worker handed its record before Ctrl-C has not sent anything yet
+        if self.shutdown.load(Ordering::SeqCst) {
+            info!("⏭️  Shutdown requested, burn {} stays pending", record.signature);
+            return None;
+        }
+        
+        info!(
+            "Processing mint: {} -> {} solXEN (raw: {})",
+            record.burner, 
+            record.amount_as_decimal(),
+            record.amount
+        );
+        
+        let result = self.mint_tokens(record).await;
+        self.mint_delay.observe(result.as_ref().is_err_and(is_rate_limited));
+        Some(self.record_outcome(record, result).await)
+    }
+    
+    /// Log and store the outcome of one mint: the attempt, and on success the
+    /// minted state, then run the post-mint hooks. Returns whether the mint landed.
+    async fn record_outcome(&self, record: &BurnRecord, result: Result<MintOutcome>) -> bool {
+        match result {
+            Ok(MintOutcome { signature, amount, fee_lamports }) => {
+                METRICS.mints_total.inc();
+                info!("✅ Mint transaction successful!");
+                info!("   Burner: {}", record.burner);
+                info!("   Amount: {} solXEN ({} raw units)", record.amount_as_decimal(), record.amount);
+                if amount != record.amount {
+                    info!("   Minted: {} solXEN ({} raw units)", raw_to_solxen(amount), amount);
+                }
+                info!("   Burn Signature: {}", record.signature);
+                info!("   Mint Signature: {}", signature);
+                info!("   X1 Explorer: {}", self.config.x1_tx_url(&signature));
+                if let Some(fee) = fee_lamports {
+                    info!("   Fee: {} lamports", fee);
+                }
+                
+                if let Err(e) = self.db.record_mint_attempt(&record.signature, Some(&signature), None, fee_lamports).await {
+                    warn!("Failed to record mint attempt for {}: {}", record.signature, e);
+                }
+                
+                if let Err(e) = self.db.mark_as_minted(&record.signature, &signature, Some(amount), fee_lamports).await {
+                    error!("❌ Failed to update mint status in database: {}", e);
+                } else {
+                    METRICS.pending_mints.dec();
+                    info!("✅ Database updated successfully");
+                    
+                    let (hooks, minted) = (self.hooks.clone(), record.clone());
+                    self.spawn_hooks(record, async move {
+                        for hook in hooks.iter() {
+                            hook.on_mint_success(&minted, &signature, amount).await;
+                        }
+                    });
+                }
+                true
+            }
+            Err(e) => {
+                METRICS.mint_failures_total.inc();
+                // {:#} keeps the underlying RPC reason behind the added context
+                let reason = format!("{:#}", e);
+                error!("❌ Mint failed for {}: {}", record.burner, reason);
+                if let Err(db_err) = self.db.record_mint_attempt(&record.signature, None, Some(&reason), None).await {
+                    warn!("Failed to record mint attempt for {}: {}", record.signature, db_err);
+                }
+                error!("   Burn Signature: {}", record.signature);
+                error!("   Amount: {} solXEN ({} raw units)", record.amount_as_decimal(), record.amount);
+                let (hooks, failed) = (self.hooks.clone(), record.clone());
+                self.spawn_hooks(record, async move {
+                    for hook in hooks.iter() {
+                        hook.on_mint_failure(&failed, &reason).await;
+                    }
+                });
+                false
+            }
+        }
+    }
+    
+    /// Run `hooks` for `record` in the background, so a slow hook doesn't keep
+    /// its mint worker, and the permit it holds, from moving on
+    fn spawn_hooks(&self, record: &BurnRecord, hooks: impl Future<Output = ()> + Send + 'static) {
+        if self.hooks.is_empty() {
+            return;
+        }
+        let (burner, signature) = (record.burner.clone(), record.signature.clone());
+        self.hook_runs.lock().unwrap().spawn(async move {
+            logging::with_mint_context(&burner, &signature, hooks).await;
+        });
+    }
+    
+    /// Wait for every hook spawned so far, so none is cut off when the run ends
+    async fn join_hooks(&self) {
+        let mut runs = std::mem::take(&mut *self.hook_runs.lock().unwrap());
+        while let Some(result) = runs.join_next().await {
+            if let Err(e) = result {
+                warn!("Post-mint hook did not finish: {}", e);
+            }
+        }
+    }
+    
+    /// Reconcile mint transactions that a previous run signed but never recorded.
+    ///
+    /// `mint_tokens` writes each transaction's signature and blockhash to
+    /// `pending_mint_log` before broadcasting, and `mark_as_minted` removes the
+    /// entry. Any entry still present at startup is checked on-chain:
+    /// - landed successfully: the burn is marked as minted with that signature
+    /// - failed, or unseen with an expired blockhash: it can never land, so the
+    ///   entry is dropped and the burn is minted again
+    /// - unseen but its blockhash is still valid: it may yet land, so the burn
+    ///   is held back this run and returned in the set
+    ///
+    /// Together this makes minting exactly-once as long as a single process
+    /// mints from the database: a burn is only sent again once its previous
+    /// transaction is known to have failed or can no longer land. A crash
+    /// before the entry is written sends nothing, and a crash after
+    /// confirmation is settled here instead of minting twice. Burns whose
+    /// database row is changed by hand (e.g. `unmint`) are outside of this.
+    async fn reconcile_pending_log(&self) -> Result<HashSet<String>> {
+        let entries = self.db.get_pending_mint_log().await?;
+        let mut in_flight = HashSet::new();
+        
+        if entries.is_empty() {
+            return Ok(in_flight);
+        }
+        
+        info!("🔍 Reconciling {} mint transactions from a previous run", entries.len());
+        // Confirmed but not yet finalized falls through to "may still land" below
+        let landed = if self.config.require_finalized_before_mark {
+            CommitmentConfig::finalized()
+        } else {
+            CommitmentConfig::confirmed()
+        };
+        
+        for entry in entries {
+            let mint_signature = Signature::from_str(&entry.mint_signature)?;
+            let status = self.rpc
+                .call(|rpc| rpc.get_signature_statuses_with_history(&[mint_signature]))?
+                .value
+                .into_iter()
+                .next()
+                .flatten();
+            
+            match status {
+                Some(status) if status.err.is_none() && status.satisfies_commitment(landed) => {
+                    info!("   Burn {} was already minted in {}", entry.burn_signature, entry.mint_signature);
+                    let fee_lamports = self.fetch_fee(&mint_signature).await;
+                    self.db.mark_as_minted(&entry.burn_signature, &entry.mint_signature, entry.minted_amount, fee_lamports).await?;
+                }
+                Some(status) if status.err.is_some() => {
+                    warn!("   Mint {} for burn {} failed on-chain, will retry", entry.mint_signature, entry.burn_signature);
+                    self.db.clear_pending_mint(&entry.burn_signature).await?;
+                }
+                _ => {
+                    let blockhash = Hash::from_str(&entry.recent_blockhash)?;
+                    if status.is_none() && !self.rpc.call(|rpc| rpc.is_blockhash_valid(&blockhash, CommitmentConfig::processed()))? {
+                        info!("   Mint {} for burn {} never landed, will retry", entry.mint_signature, entry.burn_signature);
+                        self.db.clear_pending_mint(&entry.burn_signature).await?;
+                    } else {
+                        warn!("   Mint {} for burn {} may still land, holding back this run", entry.mint_signature, entry.burn_signature);
+                        in_flight.insert(entry.burn_signature);
+                    }
+                }
+            }
+        }
+        
+        Ok(in_flight)
     }
     
-    async fn mint_tokens(&self, record: &BurnRecord) -> Result<String> {
+    async fn mint_tokens(&self, record: &BurnRecord) -> Result<MintOutcome> {
         if self.mint_authority.is_none() {
             return self.simulate_mint(record).await;
         }
 
         let mint_authority = self.mint_authority.as_ref().unwrap();
-        let recipient = Pubkey::from_str(&record.burner)?;
+        let payer = self.fee_payer.as_deref().unwrap_or(mint_authority);
+        // An override only changes where the tokens go; the burn stays the
+        // record being minted, so bookkeeping is still keyed on its signature
+        let recipient_address = self.config.recipient_for(&record.burner);
+        if recipient_address != record.burner {
+            info!("↪️  Recipient override: minting burn {} of {} to {}", record.signature, record.burner, recipient_address);
+        }
+        if let Some(reason) = recipient_error(recipient_address) {
+            bail!(reason);
+        }
+        if self.config.exceeds_max_burn(record.amount) {
+            bail!("Burn of {} solXEN is above max_burn_amount", record.amount_as_decimal());
+        }
+        let recipient = Pubkey::from_str(recipient_address)?;
+        let amount = self.config.mint_amount_for(record.amount)?;
         
         info!(
-            "Minting {} raw units ({} solXEN) to {} on X1 testnet using Token 2022", 
-            record.amount,
-            record.amount_as_decimal(),
-            record.burner
+            "Minting {} raw units ({} solXEN) to {} on {} using Token 2022", 
+            amount,
+            raw_to_solxen(amount),
+            recipient,
+            self.config.network
         );
         
         // Token 2022 程序 ID
@@ -227,73 +1266,223 @@ impl<'a> TokenMinter<'a> {
         
         info!("Recipient token account: {}", recipient_token_account);
         
-        // 检查关联代币账户是否存在
-        let mut instructions = Vec::new();
-        
-        match self.rpc_client.get_account(&recipient_token_account) {
-            Ok(account) => {
-                info!("Associated token account already exists");
-                info!("Account owner: {}", account.owner);
-                info!("Account lamports: {}", account.lamports);
-            }
-            Err(_) => {
-                info!("Creating associated token account for recipient using Token 2022");
-                let create_ata_ix = create_associated_token_account(
-                    &mint_authority.pubkey(), // payer
-                    &recipient,               // wallet
-                    &self.token_mint,         // mint
-                    &token_program_id,        // token program (Token 2022)
-                );
-                instructions.push(create_ata_ix);
-            }
-        }
-        
-        // 创建 Token 2022 铸造指令
-        let mint_ix = token_instruction::mint_to(
-            &token_program_id,                   // Token 2022 程序 ID
-            &self.token_mint,                    // mint
-            &recipient_token_account,            // destination
-            &mint_authority.pubkey(),            // mint authority
-            &[&mint_authority.pubkey()],         // signer pubkeys
-            record.amount,                       // amount (raw units with 6 decimals)
+        let mut instructions = self.mint_instructions(
+            record,
+            &recipient,
+            &payer.pubkey(),
+            &mint_authority.pubkey(),
+            amount,
         )?;
         
-        instructions.push(mint_ix);
-        
         // 获取最新的区块哈希
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
+        let recent_blockhash = self.rpc.call_blocking(|rpc| rpc.get_latest_blockhash()).await?;
         
         // 创建并签名交易
-        let transaction = Transaction::new_signed_with_payer(
+        // The payer signs for the fees, the authority for the mint itself
+        let mut signers: Vec<&Keypair> = vec![payer];
+        if payer.pubkey() != mint_authority.pubkey() {
+            signers.push(mint_authority.as_ref());
+        }
+        let mut transaction = Transaction::new_signed_with_payer(
             &instructions,
-            Some(&mint_authority.pubkey()),
-            &[mint_authority],
+            Some(&payer.pubkey()),
+            &signers,
             recent_blockhash,
         );
         
+        if self.config.include_burn_memo && transaction_size(&transaction) > PACKET_DATA_SIZE {
+            warn!("Burn memo would push the transaction over {} bytes, sending without it", PACKET_DATA_SIZE);
+            instructions.pop();
+            transaction = Transaction::new_signed_with_payer(
+                &instructions,
+                Some(&payer.pubkey()),
+                &signers,
+                recent_blockhash,
+            );
+        }
+        
         // 发送交易
         info!("📤 Sending Token 2022 mint transaction...");
         info!("   From: {} (mint authority)", mint_authority.pubkey());
+        if self.fee_payer.is_some() {
+            info!("   Fee payer: {}", payer.pubkey());
+        }
         info!("   To: {} (recipient)", recipient);
         info!("   Token Account: {}", recipient_token_account);
-        info!("   Amount: {} solXEN ({} raw units)", record.amount_as_decimal(), record.amount);
+        info!("   Amount: {} solXEN ({} raw units)", raw_to_solxen(amount), amount);
         info!("   Token Program: {} (Token 2022)", token_program_id);
         info!("   Mint Address: {}", self.token_mint);
         
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
+        self.send_and_confirm(record, &transaction, amount).await
+    }
+    
+    /// Broadcast a signed mint transaction for `record` and wait until it is
+    /// confirmed. The intent is logged first so a crash after confirmation is
+    /// reconciled, not re-minted.
+    async fn send_and_confirm(&self, record: &BurnRecord, transaction: &Transaction, amount: u64) -> Result<MintOutcome> {
+        self.db.log_pending_mint(
+            &record.signature,
+            &transaction.signatures[0].to_string(),
+            &transaction.message.recent_blockhash.to_string(),
+            amount,
+        ).await?;
+        
+        let signed = transaction.clone();
+        let signature = self.rpc
+            .call_blocking(move |rpc| rpc.send_transaction(&signed))
+            .await
+            .inspect_err(|e| {
+                // Preflight failures carry the simulated program logs, which
+                // name the real reason (insufficient funds, owner mismatch, ...)
+                for line in program_logs(e) {
+                    error!("   Program log: {}", line);
+                }
+            })
+            .with_context(|| format!("Failed to send mint of burn {} to {}", record.signature, record.burner))?;
+        info!("   Sent, waiting for confirmation: {}", signature);
+        self.wait_for_confirmation(&signature, self.rpc.client().commitment()).await?;
+        if self.config.require_finalized_before_mark {
+            // Left in the pending log on failure, so a later run marks it once finalized
+            info!("   Confirmed, waiting for finalization: {}", signature);
+            self.wait_for_confirmation(&signature, CommitmentConfig::finalized()).await.with_context(|| {
+                format!("Mint {} is not finalized yet; burn {} stays pending until it is", signature, record.signature)
+            })?;
+        }
         
         info!("🎉 Token 2022 mint transaction confirmed!");
         info!("   Transaction Signature: {}", signature);
-        info!("   X1 Testnet Explorer: https://explorer.x1-testnet.xen.network/tx/{}", signature);
+        info!("   X1 Explorer: {}", self.config.x1_tx_url(&signature.to_string()));
+        
+        Ok(MintOutcome {
+            signature: signature.to_string(),
+            amount,
+            fee_lamports: self.fetch_fee(&signature).await,
+        })
+    }
+    
+    /// Instructions of the mint transaction for `record`: create the recipient's
+    /// token account, mint (or in transfer mode, transfer) to it and, when
+    /// enabled, the burn memo last
+    fn mint_instructions(
+        &self,
+        record: &BurnRecord,
+        recipient: &Pubkey,
+        payer: &Pubkey,
+        mint_authority: &Pubkey,
+        amount: u64,
+    ) -> Result<Vec<Instruction>> {
+        let token_program_id = spl_token_2022::id();
+        let recipient_token_account = get_associated_token_address_with_program_id(
+            recipient,
+            &self.token_mint,
+            &token_program_id,
+        );
+        
+        // Create the recipient's token account in the same transaction. The
+        // idempotent variant is a no-op when it already exists, so no lookup is
+        // needed and concurrent mints to one wallet don't collide.
+        let mut instructions = vec![create_associated_token_account_idempotent(
+            payer,                    // payer
+            recipient,                // wallet
+            &self.token_mint,         // mint
+            &token_program_id,        // token program (Token 2022)
+        )];
+        
+        let mint_ix = match &self.transfer_source {
+            // Pre-minted supply: move the tokens out of the treasury account
+            Some(source) => token_instruction::transfer_checked(
+                &token_program_id,
+                source,                          // source token account
+                &self.token_mint,                // mint
+                &recipient_token_account,        // destination
+                mint_authority,                  // owner of the source account
+                &[mint_authority],               // signer pubkeys
+                amount,
+                self.config.token_decimals,
+            )?,
+            // 创建 Token 2022 铸造指令
+            None => token_instruction::mint_to(
+                &token_program_id,                   // Token 2022 程序 ID
+                &self.token_mint,                    // mint
+                &recipient_token_account,            // destination
+                mint_authority,                      // mint authority
+                &[mint_authority],                   // signer pubkeys
+                amount,                              // amount (raw units with 6 decimals)
+            )?,
+        };
+        
+        instructions.push(mint_ix);
+        
+        // Link the mint back to its burn so explorers show the origin
+        if self.config.include_burn_memo {
+            instructions.push(spl_memo::build_memo(record.signature.as_bytes(), &[]));
+        }
         
-        Ok(signature.to_string())
+        Ok(instructions)
     }
     
-    async fn simulate_mint(&self, record: &BurnRecord) -> Result<String> {
+    /// Poll the signature until it reaches `commitment`, failing on an on-chain
+    /// error or once `confirmation_timeout_secs` have passed. A timed-out mint
+    /// stays in the pending log, so the next run reconciles it.
+    async fn wait_for_confirmation(&self, signature: &Signature, commitment: CommitmentConfig) -> Result<()> {
+        let timeout = Duration::from_secs(self.config.confirmation_timeout_secs);
+        let deadline = Instant::now() + timeout;
+        
+        let signature = *signature;
+        
+        loop {
+            let status = self.rpc
+                .call_blocking(move |rpc| rpc.get_signature_statuses(&[signature]))
+                .await?
+                .value
+                .into_iter()
+                .next()
+                .flatten();
+            
+            if let Some(status) = status {
+                if let Some(err) = status.err {
+                    bail!("Mint transaction {} failed: {}", signature, err);
+                }
+                if status.satisfies_commitment(commitment) {
+                    return Ok(());
+                }
+            }
+            
+            if Instant::now() >= deadline {
+                bail!(
+                    "Timed out after {}s waiting for mint transaction {} to reach {:?}",
+                    timeout.as_secs(), signature, commitment.commitment
+                );
+            }
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        }
+    }
+    
+    /// Fee charged for a confirmed transaction. The mint already landed, so a
+    /// lookup failure is only logged and the fee recorded as unknown.
+    async fn fetch_fee(&self, signature: &Signature) -> Option<u64> {
+        let signature = *signature;
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Json),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        
+        match self.rpc.call_blocking(move |rpc| rpc.get_transaction_with_config(&signature, config)).await {
+            Ok(transaction) => transaction.transaction.meta.map(|meta| meta.fee),
+            Err(e) => {
+                warn!("Could not fetch fee for transaction {}: {}", signature, e);
+                None
+            }
+        }
+    }
+    
+    async fn simulate_mint(&self, record: &BurnRecord) -> Result<MintOutcome> {
+        let amount = self.config.mint_amount_for(record.amount)?;
         info!("🎭 SIMULATION MODE - No real Token 2022 transaction will be sent");
         info!("   Would mint: {} raw units ({} solXEN) -> {}", 
-            record.amount,
-            record.amount_as_decimal(),
+            amount,
+            raw_to_solxen(amount),
             record.burner
         );
         info!("   Using Token 2022 program: {}", spl_token_2022::id());
@@ -317,6 +1506,301 @@ impl<'a> TokenMinter<'a> {
         info!("🎭 Simulated Token 2022 mint transaction: {}", mock_signature);
         info!("   This is a MOCK signature for testing purposes");
         
-        Ok(mock_signature)
+        Ok(MintOutcome {
+            signature: mock_signature,
+            amount,
+            fee_lamports: None,
+        })
+    }
+}
+
+/// Lamports to SOL, for logs
+fn lamports_to_sol(lamports: u64) -> Decimal {
+    Decimal::from_i128_with_scale(i128::from(lamports), 9)
+}
+
+/// Answer to a `--confirm-each` prompt
+enum Confirmation {
+    Mint,
+    Skip, // Leave the burn pending
+    Quit, // Stop the run; the remaining burns stay pending
+}
+
+/// Raw token units (6 decimals) to solXEN, for logs
+fn raw_to_solxen(raw: u64) -> Decimal {
+    Decimal::from(raw) / Decimal::from(1_000_000)
+}
+
+/// Wire size of a signed transaction: signature count, signatures, then the message
+fn transaction_size(transaction: &Transaction) -> usize {
+    1 + transaction.signatures.len() * 64 + transaction.message_data().len()
+}
+
+/// Program log lines of a failed preflight simulation, if the RPC returned any
+fn program_logs(error: &ClientError) -> &[String] {
+    match error.kind() {
+        ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => result.logs.as_deref().unwrap_or_default(),
+        _ => &[],
+    }
+}
+
+/// Whether a mint failed because the RPC is rate limiting (HTTP 429 or a
+/// "rate limit" message), rather than for something retrying won't fix
+fn is_rate_limited(error: &anyhow::Error) -> bool {
+    let is_429 = error.chain().any(|cause| {
+        cause
+            .downcast_ref::<ClientError>()
+            .and_then(|e| match e.kind() {
+                ClientErrorKind::Reqwest(e) => e.status(),
+                _ => None,
+            })
+            .is_some_and(|status| status.as_u16() == 429)
+    });
+    let message = format!("{:#}", error).to_ascii_lowercase();
+    is_429 || message.contains("rate limit") || message.contains("too many requests")
+}
+
+/// Why tokens can't be minted to `burner`, if they can't: it must be a wallet
+/// address, since an off-curve key (a PDA) has no signer to own an ATA for
+fn recipient_error(burner: &str) -> Option<String> {
+    match Pubkey::from_str(burner) {
+        Ok(pubkey) if pubkey.is_on_curve() => None,
+        Ok(_) => Some(format!("burner {} is off-curve (a program address, not a wallet)", burner)),
+        Err(e) => Some(format!("burner {} is not a valid address: {}", burner, e)),
+    }
+}
+
+/// Read a keypair file in either format accepted by `parse_keypair`
+pub fn read_keypair(path: &std::path::Path) -> Result<Keypair> {
+    let mut file = File::open(path)?;
+    let mut data = String::new();
+    file.read_to_string(&mut data)?;
+    
+    parse_keypair(&data).map_err(|e| anyhow::anyhow!("Invalid keypair file {:?}: {}", path, e))
+}
+
+/// Parse a 64-byte secret key given as a JSON byte array or a base58 string.
+/// Errors never echo the input, since it is a secret.
+fn parse_keypair(text: &str) -> Result<Keypair> {
+    let text = text.trim();
+    let bytes: Vec<u8> = if text.starts_with('[') {
+        serde_json::from_str(text).map_err(|_| anyhow::anyhow!("not a valid JSON byte array"))?
+    } else {
+        bs58::decode(text)
+            .into_vec()
+            .map_err(|_| anyhow::anyhow!("neither a JSON byte array nor a base58 string"))?
+    };
+    if bytes.len() != 64 {
+        bail!("expected a 64-byte secret key, got {} bytes", bytes.len());
+    }
+    Keypair::from_bytes(&bytes).map_err(|_| anyhow::anyhow!("bytes do not form a valid ed25519 keypair"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MemoCheck, NewBurnRecord};
+    use chrono::Utc;
+    
+    /// A minter in simulation mode (no keypair) over `db`; nothing reaches the RPC
+    fn simulation_minter(db: &Database) -> TokenMinter {
+        minter_with(db, Config::load().unwrap())
+    }
+    
+    fn minter_with(db: &Database, config: Config) -> TokenMinter {
+        TokenMinter {
+            rpc: Arc::new(RpcPool::new(&config.x1_rpc_urls, Duration::from_secs(1), CommitmentConfig::confirmed())),
+            db: db.clone(),
+            mint_authority: None,
+            fee_payer: None,
+            token_mint: Pubkey::new_unique(),
+            transfer_source: None,
+            hooks: Arc::new(Vec::new()),
+            hook_runs: Arc::default(),
+            mint_delay: Arc::new(AdaptiveDelay::new(&config)),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            config: Arc::new(config),
+        }
+    }
+    
+    fn burn(signature: &str, burner: &str) -> NewBurnRecord {
+        NewBurnRecord {
+            signature: signature.to_string(),
+            burner: burner.to_string(),
+            amount: 500_000_000,
+            memo: None,
+            token: None,
+            timestamp: None,
+            memo_checked: MemoCheck::Unchecked,
+            created_at: Utc::now(),
+        }
+    }
+    
+    #[tokio::test]
+    async fn limit_counts_only_mintable_records() {
+        let db = Database::new_in_memory().await.unwrap();
+        // The unmintable burn comes first, so cutting before the skips would spend a slot on it
+        for (signature, burner) in [("unmintable", "not-an-address"), ("burn-1", ""), ("burn-2", ""), ("burn-3", "")] {
+            let burner = if burner.is_empty() { Keypair::new().pubkey().to_string() } else { burner.to_string() };
+            db.insert_burn_record(&burn(signature, &burner)).await.unwrap();
+        }
+        
+        let mut config = Config::load().unwrap();
+        config.mint_limit = Some(2);
+        config.mint_delay_ms = 0;
+        config.min_burn_amount = 1;
+        let mut minter = minter_with(&db, config);
+        let summary = minter.process_pending_mints(None).await.unwrap();
+        
+        assert_eq!(summary.pending, 4);
+        assert_eq!((summary.attempted, summary.succeeded, summary.skipped), (2, 2, 1));
+        let pending: Vec<_> = db.get_pending_mints(0).await.unwrap().into_iter().map(|record| record.signature).collect();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.contains(&"unmintable".to_string()));
+    }
+    
+    /// Finishes only once as many mints as the barrier counts have reached it
+    struct RendezvousHook(Arc<tokio::sync::Barrier>);
+    
+    #[async_trait::async_trait]
+    impl PostMintHook for RendezvousHook {
+        async fn on_mint_success(&self, _record: &BurnRecord, _signature: &str, _minted_amount: u64) {
+            self.0.wait().await;
+        }
+    }
+    
+    #[tokio::test]
+    async fn hooks_run_without_holding_the_worker() {
+        let db = Database::new_in_memory().await.unwrap();
+        for signature in ["burn-1", "burn-2"] {
+            db.insert_burn_record(&burn(signature, &Keypair::new().pubkey().to_string())).await.unwrap();
+        }
+        
+        let mut config = Config::load().unwrap();
+        config.mint_concurrency = 1;
+        config.mint_delay_ms = 0;
+        config.min_burn_amount = 1;
+        let mut minter = minter_with(&db, config);
+        // The first mint's hook waits for the second's, which a single worker
+        // only gets to once the first hook has let go of it
+        let arrived = Arc::new(tokio::sync::Barrier::new(2));
+        minter.hooks = Arc::new(vec![Box::new(RendezvousHook(arrived.clone()))]);
+        let summary = tokio::time::timeout(Duration::from_secs(10), minter.process_pending_mints(None))
+            .await
+            .expect("a hook held up the next mint")
+            .unwrap();
+        
+        assert_eq!(summary.succeeded, 2);
+        assert!(minter.hook_runs.lock().unwrap().is_empty());
+    }
+    
+    #[tokio::test]
+    async fn shutdown_before_send_leaves_the_burn_pending() {
+        let db = Database::new_in_memory().await.unwrap();
+        db.insert_burn_record(&burn("burn-1", "burner-1")).await.unwrap();
+        let record = db.get_pending_mints(0).await.unwrap().remove(0);
+        
+        let minter = simulation_minter(&db);
+        minter.shutdown.store(true, Ordering::SeqCst);
+        assert_eq!(minter.process_record(&record).await, None);
+        
+        let pending = db.get_pending_mints(0).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].signature, "burn-1");
+        assert!(db.get_attempts_for("burn-1").await.unwrap().is_empty());
+        
+        let mut summary = MintSummary::default();
+        summary.add(Ok(None));
+        assert_eq!((summary.attempted, summary.failed, summary.skipped), (0, 0, 1));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn delay_stays_at_mint_delay_ms_without_a_floor() {
+        let mut config = Config::load().unwrap();
+        config.mint_delay_ms = 500;
+        config.mint_delay_min_ms = None;
+        let delay = AdaptiveDelay::new(&config);
+        delay.observe(false);
+        assert_eq!(delay.current(), Duration::from_millis(500));
+        
+        config.mint_delay_min_ms = Some(0);
+        let delay = AdaptiveDelay::new(&config);
+        delay.observe(false);
+        assert_eq!(delay.current(), Duration::from_millis(400));
+    }
+    
+    #[test]
+    fn rate_limit_needs_more_than_429_in_the_message() {
+        assert!(!is_rate_limited(&anyhow::anyhow!("Blockhash 4290xyz not found")));
+        assert!(is_rate_limited(&anyhow::anyhow!("RPC says: Too Many Requests")));
+    }
+    
+    #[test]
+    fn keypair_from_json_byte_array() {
+        let keypair = Keypair::new();
+        let json = serde_json::to_string(&keypair.to_bytes().to_vec()).unwrap();
+        assert_eq!(parse_keypair(&json).unwrap().pubkey(), keypair.pubkey());
+        // As solana-keygen writes it, with surrounding whitespace
+        assert_eq!(parse_keypair(&format!("  {}\n", json)).unwrap().pubkey(), keypair.pubkey());
+        
+        let short = serde_json::to_string(&keypair.to_bytes()[..32].to_vec()).unwrap();
+        assert_eq!(parse_keypair(&short).unwrap_err().to_string(), "expected a 64-byte secret key, got 32 bytes");
+    }
+    
+    #[test]
+    fn keypair_from_base58_string() {
+        let keypair = Keypair::new();
+        let base58 = keypair.to_base58_string();
+        assert_eq!(parse_keypair(&base58).unwrap().pubkey(), keypair.pubkey());
+        
+        // Mangled secrets are rejected without echoing them
+        let mangled = format!("{}0", &base58[1..]);
+        let error = parse_keypair(&mangled).unwrap_err().to_string();
+        assert!(!error.contains(&mangled[..16]), "{}", error);
+    }
+    
+    #[test]
+    fn keypair_from_file_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let keypair = Keypair::new();
+        
+        let json_path = dir.path().join("id.json");
+        std::fs::write(&json_path, serde_json::to_string(&keypair.to_bytes().to_vec()).unwrap()).unwrap();
+        assert_eq!(read_keypair(&json_path).unwrap().pubkey(), keypair.pubkey());
+        
+        let base58_path = dir.path().join("id.b58");
+        std::fs::write(&base58_path, format!("{}\n", keypair.to_base58_string())).unwrap();
+        assert_eq!(read_keypair(&base58_path).unwrap().pubkey(), keypair.pubkey());
+        
+        let garbage_path = dir.path().join("garbage.json");
+        std::fs::write(&garbage_path, "not a key").unwrap();
+        let error = read_keypair(&garbage_path).unwrap_err().to_string();
+        assert!(error.contains("garbage.json"), "{}", error);
+        
+        // No file means simulation mode rather than an error
+        assert!(TokenMinter::load_keypair_from(None, &dir.path().join("missing.json")).unwrap().is_none());
+        assert_eq!(TokenMinter::load_keypair_from(None, &json_path).unwrap().unwrap().pubkey(), keypair.pubkey());
+        assert_eq!(
+            TokenMinter::load_keypair_from(Some("  ".to_string()), &json_path).unwrap().unwrap().pubkey(),
+            keypair.pubkey()
+        );
+    }
+    
+    #[test]
+    fn keypair_from_env_wins_over_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_keypair = Keypair::new();
+        let json_path = dir.path().join("id.json");
+        std::fs::write(&json_path, serde_json::to_string(&file_keypair.to_bytes().to_vec()).unwrap()).unwrap();
+        
+        let env_keypair = Keypair::new();
+        let loaded = TokenMinter::load_keypair_from(Some(env_keypair.to_base58_string()), &json_path).unwrap().unwrap();
+        assert_eq!(loaded.pubkey(), env_keypair.pubkey());
+        
+        let error = TokenMinter::load_keypair_from(Some("not a key".to_string()), &json_path).unwrap_err().to_string();
+        assert!(error.contains(MINT_AUTHORITY_ENV), "{}", error);
+    }
+}
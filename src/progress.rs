@@ -0,0 +1,102 @@
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use log::{info, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+/// How often progress is logged when no bar is drawn
+const LOG_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Every bar is drawn through this, so log lines can be printed above the bars
+static BARS: LazyLock<MultiProgress> = LazyLock::new(MultiProgress::new);
+static BARS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Install `logger` as the global logger, printing each line above any active bar
+pub fn init_logger(logger: env_logger::Logger) -> Result<(), SetLoggerError> {
+    log::set_max_level(logger.filter());
+    log::set_boxed_logger(Box::new(BarAwareLogger { inner: logger }))
+}
+
+/// Never draw bars in this process (`--no-progress`)
+pub fn disable_bars() {
+    BARS_ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Bars only make sense on an interactive terminal, and at debug/trace level
+/// the log volume would bury them anyway
+fn bars_enabled() -> bool {
+    BARS_ENABLED.load(Ordering::Relaxed) && std::io::stderr().is_terminal() && log::max_level() < LevelFilter::Debug
+}
+
+struct BarAwareLogger {
+    inner: env_logger::Logger,
+}
+
+impl Log for BarAwareLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.inner.enabled(record.metadata()) {
+            BARS.suspend(|| self.inner.log(record));
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Progress through a known number of items: a bar with counts and ETA on a
+/// terminal, otherwise a log line every few seconds
+pub struct Progress {
+    bar: Option<ProgressBar>,
+    label: &'static str,
+    total: u64,
+    done: u64,
+    last_log: Instant,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: u64) -> Self {
+        let bar = bars_enabled().then(|| {
+            let bar = BARS.add(ProgressBar::new(total));
+            bar.set_style(
+                ProgressStyle::with_template("{msg:>10} [{bar:40.cyan/blue}] {pos}/{len} (ETA {eta})")
+                    .expect("progress template is valid")
+                    .progress_chars("=> "),
+            );
+            bar.set_message(label);
+            bar
+        });
+
+        Progress {
+            bar,
+            label,
+            total,
+            done: 0,
+            last_log: Instant::now(),
+        }
+    }
+
+    pub fn inc(&mut self) {
+        self.done += 1;
+        match &self.bar {
+            Some(bar) => bar.inc(1),
+            None if self.last_log.elapsed() >= LOG_INTERVAL => {
+                info!("{}: {}/{}", self.label, self.done, self.total);
+                self.last_log = Instant::now();
+            }
+            None => {}
+        }
+    }
+
+    pub fn finish(self) {
+        if let Some(bar) = self.bar {
+            bar.finish_and_clear();
+            BARS.remove(&bar);
+        }
+    }
+}
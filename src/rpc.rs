@@ -0,0 +1,94 @@
+use log::{info, warn};
+use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcError;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// X1 RPC endpoints in order of preference. A call that fails because an
+/// endpoint is unreachable, timed out or rate limiting moves on to the next
+/// one, and whichever endpoint answered keeps serving later calls.
+pub struct RpcPool {
+    clients: Vec<RpcClient>,
+    current: AtomicUsize,
+}
+
+impl RpcPool {
+    pub fn new(urls: &[String], timeout: Duration, commitment: CommitmentConfig) -> Self {
+        let clients = urls
+            .iter()
+            .map(|url| RpcClient::new_with_timeout_and_commitment(url.clone(), timeout, commitment))
+            .collect();
+        RpcPool { clients, current: AtomicUsize::new(0) }
+    }
+
+    /// Every endpoint, in configured order
+    pub fn endpoints(&self) -> &[RpcClient] {
+        &self.clients
+    }
+
+    /// The endpoint calls currently go to
+    pub fn client(&self) -> &RpcClient {
+        &self.clients[self.current.load(Ordering::Relaxed) % self.clients.len()]
+    }
+
+    /// Run `call` against the current endpoint, failing over to the others in
+    /// turn. Any other error is returned as is, since another endpoint would
+    /// give the same answer.
+    pub fn call<T>(&self, call: impl Fn(&RpcClient) -> ClientResult<T>) -> ClientResult<T> {
+        let start = self.current.load(Ordering::Relaxed);
+        let mut attempt = 0;
+        loop {
+            let index = (start + attempt) % self.clients.len();
+            let client = &self.clients[index];
+            match call(client) {
+                Ok(value) => {
+                    if attempt > 0 {
+                        self.current.store(index, Ordering::Relaxed);
+                        info!("🔀 Switched X1 RPC to {}", client.url());
+                    }
+                    return Ok(value);
+                }
+                Err(e) if attempt + 1 < self.clients.len() && is_endpoint_failure(&e) => {
+                    let next = &self.clients[(index + 1) % self.clients.len()];
+                    warn!("⚠️  X1 RPC {} failed ({}), trying {}", client.url(), e, next.url());
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    /// `call` on the blocking thread pool, for use inside async tasks: the
+    /// client blocks on every request, which would otherwise stall the runtime
+    /// worker and every other task scheduled on it
+    pub async fn call_blocking<T, F>(self: &Arc<Self>, call: F) -> ClientResult<T>
+    where
+        T: Send + 'static,
+        F: Fn(&RpcClient) -> ClientResult<T> + Send + 'static,
+    {
+        let pool = self.clone();
+        tokio::task::spawn_blocking(move || pool.call(call))
+            .await
+            .unwrap_or_else(|e| Err(ClientErrorKind::Custom(format!("X1 RPC task failed: {}", e)).into()))
+    }
+}
+
+/// Whether the endpoint itself is at fault: the connection failed or timed
+/// out, or it answered 429 Too Many Requests
+fn is_endpoint_failure(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Io(_) => true,
+        ClientErrorKind::Reqwest(e) => {
+            e.is_connect() || e.is_timeout() || e.status().is_some_and(|status| status.as_u16() == 429)
+        }
+        // Some calls ask for the node version first and only keep the message
+        // of a failed request
+        ClientErrorKind::RpcError(RpcError::RpcRequestError(message)) => {
+            message.starts_with("cluster version query failed")
+        }
+        _ => false,
+    }
+}
@@ -1,21 +1,93 @@
 use chrono::{DateTime, Utc};
+use clap::ValueEnum;
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Column wallet summaries are ranked by, largest (or latest) first
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletOrder {
+    /// Unminted burned amount, the default
+    #[default]
+    Burned,
+    Minted,
+    BurnCount,
+    LastMint,
+}
+
+impl WalletOrder {
+    /// Stable name passed to the queries
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WalletOrder::Burned => "burned",
+            WalletOrder::Minted => "minted",
+            WalletOrder::BurnCount => "burn_count",
+            WalletOrder::LastMint => "last_mint",
+        }
+    }
+}
+
+/// Mint state records can be filtered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStatus {
+    /// Not minted yet, failed attempts included (like the pending statistic)
+    Pending,
+    Minted,
+    /// Not minted yet with at least one failed attempt
+    Failed,
+}
+
+impl RecordStatus {
+    /// Stable name passed to the queries
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordStatus::Pending => "pending",
+            RecordStatus::Minted => "minted",
+            RecordStatus::Failed => "failed",
+        }
+    }
+}
+
+/// Optional constraints for `get_records_filtered`; unset fields match
+/// every record
+#[derive(Debug, Clone, Default)]
+pub struct RecordFilter {
+    pub min_amount: Option<u64>, // Raw units, inclusive
+    pub max_amount: Option<u64>, // Raw units, inclusive
+    pub status: Option<RecordStatus>,
+    pub since: Option<DateTime<Utc>>, // Created or minted after this
+    pub burner: Option<String>,
+}
+
+impl RecordFilter {
+    /// Bounds passed to the amount range query; always bound so the
+    /// `idx_amount` index can serve it
+    pub fn amount_range(&self) -> (i64, i64) {
+        let min = self.min_amount.map_or(0, |amount| amount.min(i64::MAX as u64) as i64);
+        let max = self.max_amount.map_or(i64::MAX, |amount| amount.min(i64::MAX as u64) as i64);
+        (min, max)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BurnRecord {
     pub id: Option<i64>,
     pub signature: String,
     pub burner: String,
+    #[serde(flatten, with = "amount_fields")]
     pub amount: u64, // Raw amount with 6 decimals (for X1)
     pub memo: Option<String>,
     pub token: Option<String>,
     pub timestamp: Option<DateTime<Utc>>,
-    pub memo_checked: Option<String>,
+    pub memo_checked: MemoCheck,
     pub created_at: DateTime<Utc>,
     pub is_minted: bool,
     pub minted_time: Option<DateTime<Utc>>,
     pub minted_signature: Option<String>,
+    #[serde(flatten, with = "minted_amount_fields")]
+    pub minted_amount: Option<u64>, // Raw units actually minted, which can differ from `amount`
 }
 
 impl BurnRecord {
@@ -23,6 +95,114 @@ impl BurnRecord {
     pub fn amount_as_decimal(&self) -> Decimal {
         Decimal::from(self.amount) / Decimal::from(1_000_000)
     }
+    
+    /// Minted amount in solXEN; unset until the burn is minted
+    pub fn minted_amount_as_decimal(&self) -> Option<Decimal> {
+        self.minted_amount.map(|amount| Decimal::from(amount) / Decimal::from(1_000_000))
+    }
+}
+
+/// Write a raw amount under `key` and its exact solXEN value as a string under
+/// `decimal_key`. Readers that parse JSON numbers as doubles round large raw
+/// amounts; the string never does.
+fn serialize_raw_amount<S: Serializer>(
+    serializer: S,
+    key: &'static str,
+    decimal_key: &'static str,
+    raw: Option<u64>,
+) -> Result<S::Ok, S::Error> {
+    let decimal = raw.map(|raw| Decimal::from_i128_with_scale(i128::from(raw), 6).normalize().to_string());
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry(key, &raw)?;
+    map.serialize_entry(decimal_key, &decimal)?;
+    map.end()
+}
+
+/// `BurnRecord::amount` as `amount` (raw) plus `amount_decimal`; reading only needs `amount`
+mod amount_fields {
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    pub fn serialize<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_raw_amount(serializer, "amount", "amount_decimal", Some(*amount))
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields {
+            amount: u64,
+        }
+        Ok(Fields::deserialize(deserializer)?.amount)
+    }
+}
+
+/// `BurnRecord::minted_amount` as `minted_amount` (raw) plus `minted_amount_decimal`
+mod minted_amount_fields {
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    pub fn serialize<S: Serializer>(amount: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_raw_amount(serializer, "minted_amount", "minted_amount_decimal", *amount)
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields {
+            #[serde(default)]
+            minted_amount: Option<u64>,
+        }
+        Ok(Fields::deserialize(deserializer)?.minted_amount)
+    }
+}
+
+/// Whether the burn's memo was verified, from the `memo_checked` column:
+/// 'Y' verified, 'N' checked and rejected, NULL never checked. The source
+/// spells these several ways; anything unrecognized counts as unchecked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoCheck {
+    Verified,
+    Rejected,
+    #[default]
+    Unchecked,
+}
+
+impl MemoCheck {
+    pub fn from_column(value: Option<&str>) -> Self {
+        match value.map(|value| value.trim().to_ascii_uppercase()).as_deref() {
+            Some("Y" | "YES" | "T" | "TRUE" | "1") => MemoCheck::Verified,
+            Some("N" | "NO" | "F" | "FALSE" | "0") => MemoCheck::Rejected,
+            _ => MemoCheck::Unchecked,
+        }
+    }
+    
+    /// Value stored in `memo_checked`
+    pub fn as_column(self) -> Option<&'static str> {
+        match self {
+            MemoCheck::Verified => Some("Y"),
+            MemoCheck::Rejected => Some("N"),
+            MemoCheck::Unchecked => None,
+        }
+    }
+}
+
+/// Records per `MemoCheck` state
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct MemoCheckCounts {
+    pub verified: i64,
+    pub rejected: i64,
+    pub unchecked: i64,
+}
+
+/// A burn ready to be written to the destination database; it starts unminted
+#[derive(Debug)]
+pub struct NewBurnRecord {
+    pub signature: String,
+    pub burner: String,
+    pub amount: u64, // Raw amount with 6 decimals
+    pub memo: Option<String>,
+    pub token: Option<String>,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub memo_checked: MemoCheck,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -44,4 +224,37 @@ pub struct Statistics {
     pub unique_wallets: i64,
     pub pending_mints: i64,
     pub successful_mints: i64,
+    pub failed_mints: i64, // Unminted burns with at least one failed attempt
+    pub total_fees_lamports: u64, // Fees paid for recorded mints; simulated mints count as zero
+    pub success_rate: Option<f64>, // successful / (successful + failed); None before any mint
+    pub memo_checks: MemoCheckCounts,
+}
+
+/// A mint transaction that was signed and about to be broadcast, recorded so a
+/// crash before `mark_as_minted` can be reconciled on the next run
+#[derive(Debug)]
+pub struct PendingMintLogEntry {
+    pub burn_signature: String,
+    pub mint_signature: String,
+    pub recent_blockhash: String,
+    pub minted_amount: Option<u64>, // Raw units the transaction mints; unset for entries logged by older versions
+}
+
+/// An unminted burn whose mint attempts have failed so far
+#[derive(Debug)]
+pub struct FailedMint {
+    pub record: BurnRecord,
+    pub failed_attempts: i64,
+}
+
+/// One row of the `mint_attempts` audit trail; `result` is "success" or "failure"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MintAttempt {
+    pub id: i64,
+    pub burn_signature: String,
+    pub attempted_at: DateTime<Utc>,
+    pub result: String,
+    pub tx_signature: Option<String>,
+    pub error: Option<String>,
+    pub fee_lamports: Option<u64>,
 }
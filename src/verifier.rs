@@ -0,0 +1,158 @@
+use anyhow::Result;
+use log::{info, warn};
+use rust_decimal::Decimal;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::database::Database;
+use crate::rpc::RpcPool;
+use crate::types::WalletSummary;
+
+/// Compares each minted wallet's on-chain token balance with the database totals
+pub struct BalanceVerifier<'a> {
+    db: &'a Database,
+    rpc: RpcPool,
+    token_mint: Pubkey,
+    recipient_overrides: HashMap<String, String>, // Redirected wallets are checked at their override
+}
+
+pub struct VerificationSummary {
+    pub matched: usize,
+    pub mismatched: usize,
+    pub unverified: usize, // Balance could not be read, so neither matched nor mismatched
+}
+
+/// What one recipient should hold: everything minted for the burners it receives for
+#[derive(Debug, Default, PartialEq)]
+struct ExpectedBalance {
+    total_minted: Decimal,
+    burners: Vec<String>,
+}
+
+impl<'a> BalanceVerifier<'a> {
+    pub fn new(config: &Config, db: &'a Database) -> Result<Self> {
+        let rpc = RpcPool::new(
+            &config.x1_rpc_urls,
+            Duration::from_secs(config.rpc_timeout_secs),
+            CommitmentConfig::confirmed(),
+        );
+        let token_mint = Pubkey::from_str(&config.token_mint)?;
+
+        Ok(Self {
+            db,
+            rpc,
+            token_mint,
+            recipient_overrides: config.recipient_overrides.clone(),
+        })
+    }
+
+    pub async fn verify(&self) -> Result<VerificationSummary> {
+        let wallets = self.db.get_wallet_summaries().await?;
+        let token_program_id = spl_token_2022::id();
+        let mut summary = VerificationSummary {
+            matched: 0,
+            mismatched: 0,
+            unverified: 0,
+        };
+
+        for (recipient, expected) in expected_balances(&wallets, &self.recipient_overrides) {
+            let owner = Pubkey::from_str(&recipient)?;
+            let token_account = get_associated_token_address_with_program_id(
+                &owner,
+                &self.token_mint,
+                &token_program_id,
+            );
+            let burners = expected.burners.join(", ");
+
+            let on_chain = match self.rpc.call(|rpc| rpc.get_token_account_balance(&token_account)) {
+                Ok(balance) => Decimal::from_str(&balance.amount)? / Decimal::from(1_000_000),
+                Err(e) => {
+                    summary.unverified += 1;
+                    warn!("⚠️  Could not read token account {} for {}, not verified: {}", token_account, burners, e);
+                    continue;
+                }
+            };
+
+            let label = if expected.burners == [recipient.as_str()] {
+                recipient.clone()
+            } else {
+                format!("{} (receiving for {})", recipient, burners)
+            };
+            if on_chain == expected.total_minted {
+                summary.matched += 1;
+                info!("✅ {} balance matches: {} solXEN", label, on_chain);
+            } else {
+                summary.mismatched += 1;
+                let reason = if on_chain < expected.total_minted {
+                    "tokens moved away or a recorded mint never landed"
+                } else {
+                    "wallet holds more than was minted by this tool"
+                };
+                println!(
+                    "❌ {}: database {} solXEN, on-chain {} solXEN ({})",
+                    label, expected.total_minted, on_chain, reason
+                );
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Minted totals per receiving wallet. Burners redirected to the same address
+/// are summed, since their mints all land in that one token account.
+fn expected_balances(
+    wallets: &[WalletSummary],
+    recipient_overrides: &HashMap<String, String>,
+) -> BTreeMap<String, ExpectedBalance> {
+    let mut expected: BTreeMap<String, ExpectedBalance> = BTreeMap::new();
+    for wallet in wallets.iter().filter(|w| w.mint_count > 0) {
+        let recipient = recipient_overrides.get(&wallet.wallet_address).unwrap_or(&wallet.wallet_address);
+        let balance = expected.entry(recipient.clone()).or_default();
+        balance.total_minted += wallet.total_minted;
+        balance.burners.push(wallet.wallet_address.clone());
+    }
+    expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(address: &str, total_minted: i64, mint_count: i64) -> WalletSummary {
+        WalletSummary {
+            wallet_address: address.to_string(),
+            total_burned: Decimal::from(total_minted),
+            total_minted: Decimal::from(total_minted),
+            burn_count: mint_count,
+            mint_count,
+            first_burn: None,
+            last_mint: None,
+        }
+    }
+
+    #[test]
+    fn redirected_burners_are_summed_at_their_recipient() {
+        let wallets = [wallet("a", 10, 1), wallet("b", 5, 2), wallet("c", 7, 1), wallet("unminted", 3, 0)];
+        let overrides = HashMap::from([
+            ("a".to_string(), "cold".to_string()),
+            ("b".to_string(), "cold".to_string()),
+        ]);
+
+        let expected = expected_balances(&wallets, &overrides);
+
+        assert_eq!(expected.len(), 2);
+        assert_eq!(
+            expected["cold"],
+            ExpectedBalance { total_minted: Decimal::from(15), burners: vec!["a".to_string(), "b".to_string()] }
+        );
+        assert_eq!(
+            expected["c"],
+            ExpectedBalance { total_minted: Decimal::from(7), burners: vec!["c".to_string()] }
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{info, warn};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::hooks::PostMintHook;
+use crate::types::BurnRecord;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const WEBHOOK_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Payload POSTed to the configured webhook after each successful mint
+#[derive(Debug, Serialize)]
+pub struct MintNotification<'a> {
+    pub burner: &'a str,
+    pub amount: Decimal,
+    pub minted_amount: Decimal,
+    pub burn_signature: &'a str,
+    pub mint_signature: &'a str,
+    pub explorer_url: String,
+}
+
+/// Hook POSTing a `MintNotification` after each successful mint
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+    explorer_base: String, // Mint links in the payload
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str, explorer_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            client,
+            url: url.to_string(),
+            explorer_base: explorer_base.to_string(),
+        })
+    }
+
+    /// Best-effort delivery: one retry, failures are logged and never returned
+    pub async fn notify(&self, notification: &MintNotification<'_>) {
+        for attempt in 1..=2 {
+            match self.post(notification).await {
+                Ok(()) => {
+                    info!("📨 Webhook delivered for burn {}", notification.burn_signature);
+                    return;
+                }
+                Err(e) if attempt == 1 => {
+                    warn!("Webhook delivery failed, retrying: {}", e);
+                    tokio::time::sleep(WEBHOOK_RETRY_DELAY).await;
+                }
+                Err(e) => {
+                    warn!("Webhook delivery failed for burn {}: {}", notification.burn_signature, e);
+                }
+            }
+        }
+    }
+
+    async fn post(&self, notification: &MintNotification<'_>) -> Result<()> {
+        self.client
+            .post(&self.url)
+            .json(notification)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PostMintHook for WebhookNotifier {
+    async fn on_mint_success(&self, record: &BurnRecord, signature: &str, minted_amount: u64) {
+        self.notify(&MintNotification {
+            burner: &record.burner,
+            amount: record.amount_as_decimal(),
+            minted_amount: Decimal::from(minted_amount) / Decimal::from(1_000_000),
+            burn_signature: &record.signature,
+            mint_signature: signature,
+            explorer_url: format!("{}/tx/{}", self.explorer_base, signature),
+        }).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+    
+    fn notification() -> MintNotification<'static> {
+        MintNotification {
+            burner: "wallet-a",
+            amount: Decimal::new(500_000_000, 6),
+            minted_amount: Decimal::new(250_000_000, 6),
+            burn_signature: "burn-1",
+            mint_signature: "mint-1",
+            explorer_url: "https://explorer.example/tx/mint-1".to_string(),
+        }
+    }
+    
+    /// Local webhook endpoint answering each request with the next status in
+    /// `statuses` (None: never answer); request bodies are sent to the receiver
+    async fn listener(statuses: Vec<Option<u16>>) -> (String, mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (bodies, received) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0; 4096];
+                let body = loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().to_string()))
+                            .and_then(|length| length.parse().ok())
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break body.to_string();
+                        }
+                    }
+                };
+                bodies.send(body).unwrap();
+                match status {
+                    Some(status) => {
+                        let response = format!("HTTP/1.1 {} X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status);
+                        socket.write_all(response.as_bytes()).await.unwrap();
+                    }
+                    // Hold the connection open without answering until the client gives up
+                    None => {
+                        let _ = socket.read(&mut buffer).await;
+                    }
+                }
+            }
+        });
+        (url, received)
+    }
+    
+    #[tokio::test]
+    async fn posts_the_payload_and_retries_once() {
+        let (url, mut received) = listener(vec![Some(500), Some(200), Some(200)]).await;
+        let notifier = WebhookNotifier::new(&url, "https://explorer.example").unwrap();
+        notifier.notify(&notification()).await;
+        
+        let first: serde_json::Value = serde_json::from_str(&received.recv().await.unwrap()).unwrap();
+        assert_eq!(first, serde_json::json!({
+            "burner": "wallet-a",
+            "amount": "500.000000",
+            "minted_amount": "250.000000",
+            "burn_signature": "burn-1",
+            "mint_signature": "mint-1",
+            "explorer_url": "https://explorer.example/tx/mint-1",
+        }));
+        // The retry resends the same payload, and a delivered one isn't sent again
+        let retry: serde_json::Value = serde_json::from_str(&received.recv().await.unwrap()).unwrap();
+        assert_eq!(retry, first);
+        assert!(received.try_recv().is_err());
+    }
+    
+    #[tokio::test]
+    async fn gives_up_after_one_retry() {
+        let (url, mut received) = listener(vec![Some(503), Some(503), Some(200)]).await;
+        WebhookNotifier::new(&url, "https://explorer.example").unwrap().notify(&notification()).await;
+        
+        received.recv().await.unwrap();
+        received.recv().await.unwrap();
+        assert!(received.try_recv().is_err());
+    }
+    
+    #[tokio::test]
+    async fn an_unanswered_request_times_out() {
+        let (url, mut received) = listener(vec![None, None]).await;
+        let start = Instant::now();
+        WebhookNotifier::new(&url, "https://explorer.example").unwrap().notify(&notification()).await;
+        let elapsed = start.elapsed();
+        
+        // Two attempts each cut off at the timeout, with the retry pause between
+        let expected = WEBHOOK_TIMEOUT * 2 + WEBHOOK_RETRY_DELAY;
+        assert!(elapsed >= expected && elapsed < expected + Duration::from_secs(3), "took {:?}", elapsed);
+        received.recv().await.unwrap();
+        received.recv().await.unwrap();
+    }
+}